@@ -0,0 +1,60 @@
+//! These benchmarks measure the throughput of single-point, slice, and batch evaluation for a few
+//! representative functions across low, medium, and high dimensionality
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ka::{Sphere, Rastrigin, Rosenbrock, SingleObjective};
+
+/// The dimensionalities used for every function
+const DIMENSIONS: [usize; 3] = [2, 30, 137];
+
+/// The number of points evaluated in each batch
+const BATCH_SIZE: usize = 256;
+
+/// This function builds a deterministic point that is not the minimizer
+fn point(d: usize, offset: usize) -> Vec<f64> {
+    (0..d).map(|i| 0.5 + 0.01*((i + offset) % 97) as f64).collect()
+}
+
+/// This function builds a deterministic batch of points
+fn batch(d: usize) -> Vec<Vec<f64>> {
+    (0..BATCH_SIZE).map(|j| point(d, j)).collect()
+}
+
+/// This function benchmarks each evaluation entry point of one function
+fn bench_function<F: SingleObjective>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(name);
+    for &d in DIMENSIONS.iter() {
+        let x = point(d, 0);
+        let xs = batch(d);
+
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(BenchmarkId::new("f", d), &x, |b, x| {
+            b.iter(|| F::f(black_box(x.clone())))
+        });
+        group.bench_with_input(BenchmarkId::new("f_slice", d), &x, |b, x| {
+            b.iter(|| F::f_slice(black_box(x)))
+        });
+
+        group.throughput(Throughput::Elements(BATCH_SIZE as u64));
+        group.bench_with_input(BenchmarkId::new("f_batch", d), &xs, |b, xs| {
+            b.iter(|| F::f_batch(black_box(xs)))
+        });
+    }
+    group.finish();
+}
+
+fn sphere(c: &mut Criterion) {
+    bench_function::<Sphere>(c, "sphere");
+}
+
+fn rastrigin(c: &mut Criterion) {
+    bench_function::<Rastrigin>(c, "rastrigin");
+}
+
+fn rosenbrock(c: &mut Criterion) {
+    bench_function::<Rosenbrock>(c, "rosenbrock");
+}
+
+criterion_group!(benches, sphere, rastrigin, rosenbrock);
+criterion_main!(benches);
@@ -1,16 +1,64 @@
 #![warn(missing_docs)]
 #![warn(missing_doc_code_examples)]
 #![warn(clippy::all)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! The `ka` crate provides several common ***bench***marking ***fun***ctions that are commonly
 //! used to test new optimization algorithms. More specifically, the function is part of a struct
 //! that contains the objective function as well as other important information. Currently a variety
 //! of single-objective and multi-objective functions are implemented.
 
+#[cfg(not(any(feature = "std", test)))]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", test)))]
+mod prelude;
+#[cfg(not(any(feature = "std", test)))]
+use prelude::*;
+
 pub mod multi;
 pub use multi::*;
 pub mod single;
 pub use single::*;
+pub mod registry;
+pub mod wrappers;
+pub mod plot;
+#[cfg(feature = "qmc")]
+mod sobol;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// This function creates a seeded random number generator, for reproducible use of the APIs that
+/// draw random numbers
+#[cfg(feature = "sampling")]
+pub fn from_seed(seed: u64) -> rand::rngs::StdRng {
+    rand::SeedableRng::seed_from_u64(seed)
+}
+
+/// This is a record of a single evaluation of a single-objective function
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Evaluation {
+    /// The point at which the function was evaluated
+    pub point: Vec<f64>,
+
+    /// The value of the objective function at the point
+    pub value: f64,
+}
+
+/// This is a record of a single evaluation of a multi-objective function
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiEvaluation {
+    /// The point at which the function was evaluated
+    pub point: Vec<f64>,
+
+    /// The values of the objective functions at the point
+    pub values: Vec<f64>,
+}
 
 
 /// This is a trait that ensures consistent implementation of single objective benchmark functions
@@ -24,9 +72,149 @@ pub trait SingleObjective  {
     /// This function returns the minimizer (argument that will return the global minimum)
     fn minimizer(n: usize) -> Vec<f64>;
 
+    /// The global minimum in `n` dimensions, for functions whose minimum depends on the
+    /// dimensionality
+    fn minimum(_n: usize) -> f64 {
+        Self::MINIMUM
+    }
+
+    /// This function returns the optimality gap at `x`, the difference between the objective
+    /// value and the global minimum, which is used to score the output of optimizers
+    fn error(x: Vec<f64>) -> f64 {
+        let n = x.len();
+        Self::f(x) - Self::minimum(n)
+    }
+
+    /// This function returns the minimizer of a fixed-dimensional function as an array, which
+    /// avoids a heap allocation for the result
+    fn minimizer_array<const N: usize>() -> [f64; N] where Self: FixedDimensional {
+        assert_eq!(N, Self::D, "An array of size {} was requested for a function of dimensionality {}.", N, Self::D);
+        let mut minimizer = [0.0; N];
+        minimizer.copy_from_slice(&Self::minimizer(N));
+        minimizer
+    }
+
+    /// This function returns true when `x` attains the global minimum within `tol`, which also
+    /// holds for functions whose global minimum is attained on a plateau
+    fn is_minimizer(x: Vec<f64>, tol: f64) -> bool {
+        Self::error(x).abs() <= tol
+    }
+
+    /// This function returns every known global minimizer in `n` dimensions, for functions with
+    /// more than one
+    fn minimizers(n: usize) -> Vec<Vec<f64>> {
+        vec![Self::minimizer(n)]
+    }
+
+    /// This function returns the Euclidean distance from `x` to the nearest known minimizer
+    fn dist_to_minimizer(x: Vec<f64>) -> f64 {
+        Self::minimizers(x.len())
+            .iter()
+            .map(|m| x.iter().zip(m.iter()).map(|(xi, mi)| (xi - mi).powi(2)).sum::<f64>().sqrt())
+            .fold(f64::INFINITY, f64::min)
+    }
+
     /// This function is used for testing, and checks the correctness of the minimizer
     fn check_minimizer(d: usize) {
-        assert!(Self::f(Self::minimizer(d)) - Self::MINIMUM < f64::EPSILON)
+        Self::check_minimizer_within(d, f64::EPSILON)
+    }
+
+    /// This function is used for testing, and checks the correctness of the minimizer within a
+    /// tolerance, for functions whose minimum or minimizer is only known approximately
+    fn check_minimizer_within(d: usize, tol: f64) {
+        assert!((Self::f(Self::minimizer(d)) - Self::minimum(d)).abs() < tol)
+    }
+
+    /// This function approximates the gradient of the objective function with central differences
+    fn gradient(x: Vec<f64>) -> Vec<f64> {
+        let mut gradient = vec![0.0; x.len()];
+        let mut xh = x.clone();
+        for (i, gi) in gradient.iter_mut().enumerate() {
+            let h = 6e-6*x[i].abs().max(1.0);
+            xh[i] = x[i] + h;
+            let f_plus = Self::f(xh.clone());
+            xh[i] = x[i] - h;
+            let f_minus = Self::f(xh.clone());
+            xh[i] = x[i];
+            *gi = (f_plus - f_minus)/(2.0*h);
+        }
+        gradient
+    }
+
+    /// This function returns the slope of the objective function at `x` along `direction`, which
+    /// is first scaled to unit length when `normalize` is true
+    fn directional_derivative(x: Vec<f64>, direction: Vec<f64>, normalize: bool) -> f64 {
+        let slope: f64 = Self::gradient(x).iter().zip(direction.iter()).map(|(gi, di)| gi*di).sum();
+        if normalize {
+            slope/direction.iter().map(|di| di.powi(2)).sum::<f64>().sqrt()
+        } else {
+            slope
+        }
+    }
+
+    /// This function evaluates the objective function restricted to the line through `base` along
+    /// `direction`, at `f(base + t*direction)`
+    fn restrict_line(base: Vec<f64>, direction: Vec<f64>, t: f64) -> f64 {
+        Self::f(base.iter().zip(direction.iter()).map(|(bi, di)| bi + t*di).collect())
+    }
+
+    /// This function samples the objective function restricted to a line at `points` evenly spaced
+    /// values of `t` spanning `t_range`, returning one `(t, f)` pair per sample
+    fn sample_line(base: Vec<f64>, direction: Vec<f64>, t_range: (f64, f64), points: usize) -> Vec<(f64, f64)> {
+        let step = (t_range.1 - t_range.0)/((points.max(2) - 1) as f64);
+        (0..points)
+            .map(|i| {
+                let t = t_range.0 + step*(i as f64);
+                (t, Self::restrict_line(base.clone(), direction.clone(), t))
+            })
+            .collect()
+    }
+
+    /// Function for evaluating the objective function at a point held in a slice
+    fn f_slice(x: &[f64]) -> f64 {
+        Self::f(x.to_vec())
+    }
+
+    /// Function for evaluating the objective function at a batch of points
+    fn f_batch(x: &[Vec<f64>]) -> Vec<f64> {
+        x.iter().map(|xi| Self::f_slice(xi)).collect()
+    }
+
+    /// This function evaluates the objective function and records the result
+    fn evaluate_record(x: Vec<f64>) -> Evaluation {
+        Evaluation {
+            value: Self::f(x.clone()),
+            point: x,
+        }
+    }
+
+    /// Function for evaluating the objective function at a point held in an `ndarray` view, which
+    /// is read through `f_slice` when the view is contiguous and copied element by element otherwise
+    #[cfg(feature = "ndarray")]
+    fn f_ndarray(x: &ndarray::ArrayView1<f64>) -> f64 {
+        match x.as_slice() {
+            Some(slice) => Self::f_slice(slice),
+            None => Self::f(x.to_vec()),
+        }
+    }
+
+    /// Function for evaluating the objective function at a batch of points, one per row
+    #[cfg(feature = "ndarray")]
+    fn f_ndarray_batch(x: &ndarray::ArrayView2<f64>) -> ndarray::Array1<f64> {
+        x.rows().into_iter().map(|row| Self::f_ndarray(&row)).collect()
+    }
+
+    /// Function for evaluating the objective function at a point held in an `nalgebra` vector
+    #[cfg(feature = "nalgebra")]
+    fn f_nalgebra(x: &nalgebra::DVector<f64>) -> f64 {
+        Self::f_slice(x.as_slice())
+    }
+
+    /// Function for evaluating the objective function at a batch of points, one per column, which
+    /// are read through `f_slice` from the column-major storage of the matrix
+    #[cfg(feature = "nalgebra")]
+    fn f_nalgebra_batch(x: &nalgebra::DMatrix<f64>) -> nalgebra::DVector<f64> {
+        nalgebra::DVector::from_iterator(x.ncols(), x.column_iter().map(|column| Self::f_slice(column.as_slice())))
     }
 }
 
@@ -37,6 +225,44 @@ pub trait MultiObjective {
 
     /// Function for evaluating the set of objective functions
     fn f(x: Vec<f64>) -> Vec<f64>;
+
+    /// This function returns the number of objectives
+    fn num_objectives() -> usize {
+        Self::NF
+    }
+
+    /// This function is used for testing, and checks the objective values at `x` against a reference
+    /// within a tolerance
+    fn check_objectives_at(x: Vec<f64>, expected: &[f64], tol: f64) {
+        let fx = Self::f(x);
+        assert_eq!(fx.len(), expected.len());
+        for (fi, ei) in fx.iter().zip(expected.iter()) {
+            assert!((fi - ei).abs() < tol, "Expected objective {}, but found {}", ei, fi);
+        }
+    }
+
+    /// This function evaluates the set of objective functions and records the result
+    fn evaluate_record(x: Vec<f64>) -> MultiEvaluation {
+        MultiEvaluation {
+            values: Self::f(x.clone()),
+            point: x,
+        }
+    }
+}
+
+/// This is a trait that ensures consistent implementation of multi-objective benchmark functions
+/// with a known Pareto front
+pub trait ParetoFront {
+    /// This function returns a sampling of the true Pareto front, with one objective vector per point
+    fn pareto_front(num_points: usize) -> Vec<Vec<f64>>;
+
+    /// This function returns the ideal point, which holds the minimum of each objective over the
+    /// Pareto front
+    fn ideal_point() -> Vec<f64>;
+
+    /// This function returns the nadir point, which holds the maximum of each objective over the
+    /// Pareto front
+    fn nadir_point() -> Vec<f64>;
 }
 
 /// This is a trait that ensures consistent implementation of bounded benchmark functions
@@ -55,12 +281,128 @@ pub trait Bounded {
         }
         in_bounds
     }
+
+    /// This function projects a point into bounds, by clamping each coordinate to the nearest bound
+    fn clamp(x: Vec<f64>) -> Vec<f64> {
+        x.into_iter().map(|element| element.clamp(Self::BOUNDS.0, Self::BOUNDS.1)).collect()
+    }
+
+    /// This function projects a point into bounds, by reflecting each out-of-bounds coordinate back
+    /// across the bound it violates, as many times as needed
+    fn reflect(x: Vec<f64>) -> Vec<f64> {
+        let (lower, upper) = Self::BOUNDS;
+        let width = upper - lower;
+        x.into_iter()
+            .map(|element| {
+                let mut t = (element - lower) % (2.0*width);
+                if t < 0.0 {
+                    t += 2.0*width;
+                }
+                if t > width {
+                    t = 2.0*width - t;
+                }
+                lower + t
+            })
+            .collect()
+    }
+
+    /// This function maps a point from the unit hypercube to the bounds of the problem
+    fn from_unit_cube(u: Vec<f64>) -> Vec<f64> {
+        let (lower, upper) = Self::BOUNDS;
+        u.into_iter().map(|ui| lower + ui*(upper - lower)).collect()
+    }
+
+    /// This function maps a point from the bounds of the problem to the unit hypercube
+    fn to_unit_cube(x: Vec<f64>) -> Vec<f64> {
+        let (lower, upper) = Self::BOUNDS;
+        x.into_iter().map(|xi| (xi - lower)/(upper - lower)).collect()
+    }
+
+    /// This function draws `count` points in `n` dimensions uniformly at random within the bounds
+    #[cfg(feature = "sampling")]
+    fn sample<R: rand::Rng>(n: usize, count: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        (0..count).map(|_| (0..n).map(|_| rng.gen_range(Self::BOUNDS.0..=Self::BOUNDS.1)).collect()).collect()
+    }
+
+    /// This function draws a Latin Hypercube sample of `samples` points in `n` dimensions, so that
+    /// each of the `samples` equal strata of every coordinate is occupied by exactly one point
+    #[cfg(feature = "sampling")]
+    fn latin_hypercube<R: rand::Rng>(n: usize, samples: usize, rng: &mut R) -> Vec<Vec<f64>> {
+        use rand::seq::SliceRandom;
+
+        let mut u = vec![vec![0.0; n]; samples];
+        let mut strata: Vec<usize> = (0..samples).collect();
+        for j in 0..n {
+            strata.shuffle(rng);
+            for (point, stratum) in u.iter_mut().zip(strata.iter()) {
+                point[j] = ((*stratum as f64) + rng.gen::<f64>())/(samples as f64);
+            }
+        }
+        u.into_iter().map(Self::from_unit_cube).collect()
+    }
+
+    /// This function returns the first `count` points of the Sobol low-discrepancy sequence in `n`
+    /// dimensions, scaled to the bounds. The sequence starts at the lower corner of the box, and is
+    /// available in up to 21 dimensions.
+    #[cfg(feature = "qmc")]
+    fn sobol(n: usize, count: usize) -> Vec<Vec<f64>> {
+        sobol::sobol_unit(n, count).into_iter().map(Self::from_unit_cube).collect()
+    }
+}
+
+/// This is a trait that ensures consistent implementation of benchmark functions whose bounds
+/// depend on the dimensionality of the problem
+pub trait DynBounded {
+    /// The bounds of the canonical optimization problem in `n` dimensions
+    fn bounds(n: usize) -> (f64, f64);
+
+    /// Function to check bounds, which is named apart from the `in_bounds` of the other bounds
+    /// traits because every `Bounded` function also implements this trait
+    fn in_dyn_bounds(x: Vec<f64>) -> bool {
+        let (lower, upper) = Self::bounds(x.len());
+        x.iter().all(|&element| (element >= lower) && (element <= upper))
+    }
+}
+
+impl<T: Bounded> DynBounded for T {
+    fn bounds(_n: usize) -> (f64, f64) {
+        T::BOUNDS
+    }
+}
+
+/// This is a trait that ensures consistent implementation of benchmark functions with different
+/// bounds for each variable
+pub trait BoxBounded {
+    /// The bounds of each variable in the canonical optimization problem
+    const BOUNDS: &'static [(f64, f64)];
+
+    /// Function to check bounds
+    fn in_bounds(x: Vec<f64>) -> bool {
+        if x.len() != Self::BOUNDS.len() {
+            return false;
+        }
+        let mut in_bounds = true;
+        for (element, bounds) in x.iter().zip(Self::BOUNDS.iter()) {
+            if (*element < bounds.0) || (*element > bounds.1) {
+                in_bounds = false;
+                break;
+            }
+        }
+        in_bounds
+    }
+
+    /// This function returns the smallest interval that contains the bounds of every variable
+    fn enclosing_bounds() -> (f64, f64) {
+        Self::BOUNDS.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lower, upper), bounds| {
+            (lower.min(bounds.0), upper.max(bounds.1))
+        })
+    }
 }
 
 /// This is a trait that ensures consistent implementation of unbounded benchmark functions
 pub trait UnBounded {
     /// The bounds of the canonical optimization problem
-    const BOUNDS: (f64, f64) = (f64::INFINITY, f64::INFINITY);
+    const BOUNDS: (f64, f64) = (f64::NEG_INFINITY, f64::INFINITY);
 
     /// Function to check bounds
     fn in_bounds(_x: Vec<f64>) -> bool {
@@ -94,14 +436,118 @@ pub trait Constrained {
     fn g(x: Vec<f64>) -> Vec<f64> {
         Self::inequality_constraints(x)
     }
+
+    /// This function returns the total constraint violation at `x`, which sums the magnitudes of
+    /// the equality constraints and the positive parts of the inequality constraints
+    fn total_violation(x: Vec<f64>) -> f64 {
+        let equality: f64 = Self::equality_constraints(x.clone()).iter().map(|hi| hi.abs()).sum();
+        let inequality: f64 = Self::inequality_constraints(x).iter().map(|gi| gi.max(0.0)).sum();
+        equality + inequality
+    }
+}
+
+/// This is a trait for constrained multi-objective benchmark functions, which evaluates the
+/// objectives and the constraints in a single call
+pub trait ConstrainedMultiObjective: MultiObjective + Constrained {
+    /// This function returns the objectives, the equality constraints, and the inequality
+    /// constraints at `x`
+    fn evaluate_full(x: Vec<f64>) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        (Self::f(x.clone()), Self::equality_constraints(x.clone()), Self::inequality_constraints(x))
+    }
 }
 
+impl<T: MultiObjective + Constrained> ConstrainedMultiObjective for T {}
+
 /// This is a trait that ensures consistent implementation of unconstrained benchmark functions
 pub trait UnConstrained {
     /// This trait indicates that the function is unconstrained
     const CONSTRAINED: bool = false;
 }
 
+/// This is a trait that describes the landscape of a benchmark function, so that functions can be
+/// filtered by their properties
+pub trait Properties {
+    /// This constant indicates whether the function is separable in its variables
+    const SEPARABLE: bool;
+
+    /// This constant indicates whether the function is differentiable everywhere
+    const DIFFERENTIABLE: bool;
+
+    /// This constant indicates whether the function has more than one local minimum
+    const MULTIMODAL: bool;
+
+    /// This function returns the number of local minima within the canonical bounds in `n`
+    /// dimensions, or `None` where it is not known in closed form
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        None
+    }
+
+    /// This function returns the ratio of the largest to the smallest eigenvalue of the Hessian at
+    /// the global minimizer in `n` dimensions, or `None` where it is not known in closed form
+    fn condition_number(_n: usize) -> Option<f64> {
+        None
+    }
+}
+
+/// This is a trait that gives uniform access to the domain of a benchmark function, regardless of
+/// how it is bounded and whether its dimensionality is fixed, and which takes its bounds from
+/// `DynBounded`
+pub trait Domain: DynBounded {
+    /// The dimensionality required by the function, or `None` if any dimensionality is accepted
+    fn dim() -> Option<usize>;
+
+    /// The bounds of each variable in `n` dimensions, which are the `DynBounded` bounds for every
+    /// variable unless the function is box-bounded
+    fn box_bounds(n: usize) -> Vec<(f64, f64)> {
+        vec![Self::bounds(n); n]
+    }
+}
+
+/// This macro implements `Domain` for each of the given functions from its `DynBounded` bounds and
+/// the required dimensionality of `NDimensional` or `FixedDimensional`, which is all a function
+/// implementing `SingleObjective` needs to be boxed as a `DynSingleObjective`. Box-bounded functions
+/// implement `Domain` by hand to override `box_bounds`.
+#[macro_export]
+macro_rules! impl_domain {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl $crate::Domain for $t {
+                fn dim() -> Option<usize> {
+                    #[allow(unused_imports)]
+                    use $crate::{NDimensional, FixedDimensional};
+                    <$t>::required_dim()
+                }
+            }
+        )*
+    };
+}
+
+/// This is the box in which unbounded functions are initialized, in place of their infinite bounds
+const UNBOUNDED_INIT_BOUNDS: (f64, f64) = (-100.0, 100.0);
+
+/// This is a trait that recommends where optimizers should be initialized, for benchmark protocols
+/// that avoid rewarding a bias towards the center of the domain
+pub trait InitRegion: Domain + SingleObjective {
+    /// This function returns the recommended initialization box in `n` dimensions, which is the
+    /// lower half `[lower, 0]` of the bounds of each variable for functions with their minimizer at
+    /// the origin, and the full bounds otherwise. Infinite bounds are replaced by `[-100, 100]`, and
+    /// the minimizer is located in the required dimensionality, or in two dimensions, because not
+    /// every function knows its minimizer in `n` dimensions
+    fn init_region(n: usize) -> Vec<(f64, f64)> {
+        let centered = Self::minimizer(Self::dim().unwrap_or(2)).iter().all(|&xi| xi == 0.0);
+        Self::box_bounds(n)
+            .into_iter()
+            .map(|(lower, upper)| {
+                let lower = if lower.is_finite() { lower } else { UNBOUNDED_INIT_BOUNDS.0 };
+                let upper = if upper.is_finite() { upper } else { UNBOUNDED_INIT_BOUNDS.1 };
+                if centered && lower < 0.0 && upper > 0.0 { (lower, 0.0) } else { (lower, upper) }
+            })
+            .collect()
+    }
+}
+
+impl<T: Domain + SingleObjective> InitRegion for T {}
+
 /// This is a trait that ensures consistent implementation of N-dimensional benchmark functions
 pub trait NDimensional {
     /// This is a constant containing the correct dimensionality for the function
@@ -112,6 +558,22 @@ pub trait NDimensional {
 
     /// This constant describes a high value to use for testing
     const HIGH_D: usize = 137;
+
+    /// This constant is the smallest dimensionality accepted by the function
+    const MIN_D: usize = 1;
+
+    /// This function returns the dimensionality required by the function, which is `None` since
+    /// any dimensionality is accepted
+    fn required_dim() -> Option<usize> {
+        None
+    }
+
+    /// This function is used to check inputs
+    fn check_input(x: &[f64]) {
+        if x.len() < Self::MIN_D {
+            panic!("A vector with size {} was used with a function that requires at least {} dimensions.", x.len(), Self::MIN_D);
+        }
+    }
 }
 
 /// This is a trait that ensures consistent implementation of benchmark functions with fixed dimensionality
@@ -125,4 +587,407 @@ pub trait FixedDimensional {
             panic!("A vector with size {} was used with a function of dimensionality {}.", x.len(), Self::D);
         }
     }
+
+    /// This function returns the dimensionality required by the function
+    fn required_dim() -> Option<usize> {
+        Some(Self::D)
+    }
+}
+
+#[cfg(test)]
+mod minimizer_tests {
+    use super::{Sphere, Step, Rastrigin, Hartmann3, Hartmann6, AlpineN2, BohachevskyN1, Matyas, Ellipsoid, Bounded, SingleObjective};
+
+    /// A one-dimensional double well with minimizers at -1 and 1
+    struct DoubleWell {}
+
+    impl SingleObjective for DoubleWell {
+        const MINIMUM: f64 = 0.0;
+
+        fn f(x: Vec<f64>) -> f64 {
+            (x[0].powi(2) - 1.0).powi(2)
+        }
+
+        fn minimizer(_n: usize) -> Vec<f64> {
+            vec![1.0]
+        }
+
+        fn minimizers(_n: usize) -> Vec<Vec<f64>> {
+            vec![vec![-1.0], vec![1.0]]
+        }
+    }
+
+    #[test]
+    fn check_error_at_minimizer() {
+        assert!(Rastrigin::error(Rastrigin::minimizer(5)).abs() < 1e-12);
+        assert!(Hartmann3::error(Hartmann3::minimizer(3)).abs() < 1e-4);
+        assert!(AlpineN2::error(AlpineN2::minimizer(3)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn check_error_non_negative() {
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let u = vec![(i as f64)/20.0, (j as f64)/20.0];
+                assert!(Matyas::error(Matyas::from_unit_cube(u.clone())) >= 0.0);
+                assert!(Ellipsoid::error(Ellipsoid::from_unit_cube(u)) >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn check_is_minimizer() {
+        assert!(Step::is_minimizer(vec![0.0; 3], 0.0));
+        assert!(Step::is_minimizer(vec![0.4, -0.3, 0.49], 0.0));
+        assert!(!Step::is_minimizer(vec![0.4, -0.3, 0.5], 0.0));
+        assert!(Sphere::is_minimizer(vec![0.0; 3], 0.0));
+        assert!(Sphere::is_minimizer(vec![1e-4, 0.0], 1e-6));
+        assert!(!Sphere::is_minimizer(vec![0.1, 0.0], 1e-6));
+        assert!(!Sphere::is_minimizer(vec![0.4, -0.3, 0.49], 1e-6));
+    }
+
+    #[test]
+    fn check_minimizer_array() {
+        let m: [f64; 2] = BohachevskyN1::minimizer_array();
+        assert_eq!(m.to_vec(), BohachevskyN1::minimizer(2));
+        let m: [f64; 6] = Hartmann6::minimizer_array();
+        assert_eq!(m.to_vec(), Hartmann6::minimizer(6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_minimizer_array_size() {
+        let _: [f64; 3] = BohachevskyN1::minimizer_array();
+    }
+
+    #[test]
+    fn check_dist_to_minimizer() {
+        assert_eq!(Rastrigin::dist_to_minimizer(Rastrigin::minimizer(4)), 0.0);
+        assert!((Rastrigin::dist_to_minimizer(vec![3.0, 4.0]) - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_nearest_minimizer() {
+        for m in DoubleWell::minimizers(1) {
+            assert_eq!(DoubleWell::dist_to_minimizer(m), 0.0);
+        }
+        assert!((DoubleWell::dist_to_minimizer(vec![-0.75]) - 0.25).abs() < 1e-12);
+        assert!((DoubleWell::dist_to_minimizer(vec![0.5]) - 0.5).abs() < 1e-12);
+        assert!(DoubleWell::dist_to_minimizer(vec![0.0]) > 0.0);
+    }
+}
+
+#[cfg(test)]
+mod evaluation_tests {
+    use super::{Sphere, Viennet, Zdt6, SingleObjective, MultiObjective};
+
+    #[test]
+    fn single_record() {
+        let record = Sphere::evaluate_record(vec![0.0; 3]);
+        assert_eq!(record.point, vec![0.0; 3]);
+        assert_eq!(record.value, Sphere::f(vec![0.0; 3]));
+    }
+
+    #[test]
+    fn single_batch() {
+        let x = vec![vec![1.0, 2.0], vec![-0.5, 0.5], vec![0.0, 3.0]];
+        let fx = Sphere::f_batch(&x);
+        assert_eq!(fx.len(), 3);
+        for (xi, fi) in x.iter().zip(fx.iter()) {
+            assert_eq!(*fi, Sphere::f(xi.clone()));
+            assert_eq!(*fi, Sphere::f_slice(xi));
+        }
+    }
+
+    #[test]
+    fn multi_num_objectives() {
+        assert_eq!(Viennet::num_objectives(), 3);
+        assert_eq!(Zdt6::num_objectives(), 2);
+    }
+
+    #[test]
+    fn multi_record() {
+        let record = Viennet::evaluate_record(vec![1.0, 2.0]);
+        assert_eq!(record.point, vec![1.0, 2.0]);
+        assert_eq!(record.values, Viennet::f(vec![1.0, 2.0]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use super::{Evaluation, MultiEvaluation};
+
+        let record = Sphere::evaluate_record(vec![0.5, -1.5, 2.0]);
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: Evaluation = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, record);
+
+        let record = Viennet::evaluate_record(vec![1.0, 2.0]);
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: MultiEvaluation = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, record);
+    }
+}
+
+#[cfg(test)]
+mod derivative_tests {
+    use super::{Sphere, Rastrigin, Rosenbrock, SingleObjective};
+
+    #[test]
+    fn check_directional_derivative() {
+        let x = vec![1.0, -2.0, 2.0];
+        assert!((Sphere::directional_derivative(x.clone(), x.clone(), false) - 18.0).abs() < 1e-6);
+        assert!((Sphere::directional_derivative(x.clone(), x.clone(), true) - 6.0).abs() < 1e-6);
+        assert!(Sphere::directional_derivative(x, vec![2.0, 1.0, 0.0], true).abs() < 1e-6);
+    }
+
+    #[test]
+    fn check_descent() {
+        let x = vec![-1.2, 1.0];
+        let descent: Vec<f64> = Rosenbrock::gradient(x.clone()).iter().map(|gi| -gi).collect();
+        assert!(Rosenbrock::directional_derivative(x, descent, true) < 0.0);
+    }
+
+    #[test]
+    fn check_restrict_line() {
+        let (base, direction) = (vec![0.5, -1.0], vec![1.0, 2.0]);
+        assert_eq!(Rastrigin::restrict_line(base.clone(), direction.clone(), 0.0), Rastrigin::f(base.clone()));
+        assert_eq!(Sphere::restrict_line(base.clone(), direction.clone(), 0.5), Sphere::f(vec![1.0, 0.0]));
+    }
+
+    #[test]
+    fn check_sample_line() {
+        let samples = Rastrigin::sample_line(vec![0.0, 0.0], vec![1.0, 0.0], (-2.0, 2.0), 9);
+        assert_eq!(samples.len(), 9);
+        assert_eq!(samples[0].0, -2.0);
+        assert_eq!(samples[8].0, 2.0);
+        for (t, f) in samples {
+            assert!((f - Rastrigin::f(vec![t, 0.0])).abs() < 1e-12);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod ndarray_tests {
+    use super::{Sphere, Rosenbrock, SingleObjective};
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn check_single() {
+        let x = array![0.5, -1.0, 2.0, 0.25];
+        assert_eq!(Sphere::f_ndarray(&x.view()), Sphere::f(x.to_vec()));
+        assert_eq!(Rosenbrock::f_ndarray(&x.view()), Rosenbrock::f(x.to_vec()));
+    }
+
+    #[test]
+    fn check_batch() {
+        let x = Array2::from_shape_fn((5, 3), |(i, j)| (i as f64) - 0.5*(j as f64));
+        let sphere = Sphere::f_ndarray_batch(&x.view());
+        let rosenbrock = Rosenbrock::f_ndarray_batch(&x.view());
+        assert_eq!(sphere.len(), 5);
+        for (i, row) in x.rows().into_iter().enumerate() {
+            assert_eq!(sphere[i], Sphere::f(row.to_vec()));
+            assert_eq!(rosenbrock[i], Rosenbrock::f(row.to_vec()));
+        }
+    }
+
+    #[test]
+    fn check_strided() {
+        let x = Array2::from_shape_fn((5, 3), |(i, j)| (i as f64) - 0.5*(j as f64));
+        let column = x.column(1);
+        assert!(column.as_slice().is_none());
+        assert_eq!(Sphere::f_ndarray(&column), Sphere::f(column.to_vec()));
+        assert_eq!(Rosenbrock::f_ndarray(&column), Rosenbrock::f(column.to_vec()));
+    }
+}
+
+#[cfg(all(test, feature = "nalgebra"))]
+mod nalgebra_tests {
+    use super::{Sphere, Ackley, SingleObjective};
+    use nalgebra::{DMatrix, DVector};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn check_single() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..10 {
+            let x = DVector::from_fn(4, |_, _| rng.gen_range(-5.0..5.0));
+            assert_eq!(Sphere::f_nalgebra(&x), Sphere::f(x.as_slice().to_vec()));
+            assert_eq!(Ackley::f_nalgebra(&x), Ackley::f(x.as_slice().to_vec()));
+        }
+    }
+
+    #[test]
+    fn check_batch() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let x = DMatrix::from_fn(3, 5, |_, _| rng.gen_range(-5.0..5.0));
+        let sphere = Sphere::f_nalgebra_batch(&x);
+        let ackley = Ackley::f_nalgebra_batch(&x);
+        assert_eq!(sphere.len(), 5);
+        for (j, column) in x.column_iter().enumerate() {
+            assert_eq!(sphere[j], Sphere::f(column.iter().cloned().collect()));
+            assert_eq!(ackley[j], Ackley::f(column.iter().cloned().collect()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod dimension_tests {
+    use super::{Sphere, Rosenbrock, Viennet, RosenbrockConst1, NDimensional, FixedDimensional};
+
+    #[test]
+    fn check_required_dim() {
+        assert_eq!(Sphere::required_dim(), None);
+        assert_eq!(Viennet::required_dim(), Some(2));
+        assert_eq!(RosenbrockConst1::required_dim(), Some(2));
+    }
+
+    #[test]
+    fn check_min_dim() {
+        assert_eq!(Sphere::MIN_D, 1);
+        assert_eq!(Rosenbrock::MIN_D, 2);
+        Sphere::check_input(&[0.5]);
+        Rosenbrock::check_input(&[0.5, 0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 1 dimensions")]
+    fn check_empty() {
+        Sphere::check_input(&[]);
+    }
+}
+
+#[cfg(test)]
+mod bounded_tests {
+    use super::{Rastrigin, Rosenbrock, Zdt6, Bounded};
+
+    #[test]
+    fn check_clamp() {
+        assert_eq!(Rastrigin::clamp(vec![-6.0, 0.5, 6.0]), vec![-5.12, 0.5, 5.12]);
+        assert_eq!(Zdt6::clamp(vec![-0.25, 0.0, 1.0, 1.25]), vec![0.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn check_reflect() {
+        assert_eq!(Zdt6::reflect(vec![-0.25, 0.5, 1.25]), vec![0.25, 0.5, 0.75]);
+        assert_eq!(Zdt6::reflect(vec![0.0, 1.0]), vec![0.0, 1.0]);
+        assert!((Zdt6::reflect(vec![2.25])[0] - 0.25).abs() < 1e-12);
+        assert!((Zdt6::reflect(vec![-1.75])[0] - 0.25).abs() < 1e-12);
+        let x = Rastrigin::reflect(vec![-6.0, 1.0, 6.0]);
+        assert!((x[0] + 4.24).abs() < 1e-12 && x[1] == 1.0 && (x[2] - 4.24).abs() < 1e-12);
+        assert!(Rastrigin::in_bounds(x));
+    }
+
+    #[test]
+    fn check_unit_cube() {
+        let u = vec![0.0, 0.1, 0.25, 0.5, 0.9, 1.0];
+        for (ui, vi) in u.iter().zip(Rosenbrock::to_unit_cube(Rosenbrock::from_unit_cube(u.clone()))) {
+            assert!((ui - vi).abs() < 1e-12);
+        }
+        assert_eq!(Rosenbrock::from_unit_cube(vec![0.5; 3]), vec![2.5; 3]);
+        assert_eq!(Rastrigin::from_unit_cube(vec![0.0, 0.5, 1.0]), vec![-5.12, 0.0, 5.12]);
+        assert_eq!(Rastrigin::to_unit_cube(vec![-5.12, 5.12]), vec![0.0, 1.0]);
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn check_sample_seeded() {
+        use crate::from_seed;
+
+        let a = Rastrigin::sample(3, 50, &mut from_seed(42));
+        let b = Rastrigin::sample(3, 50, &mut from_seed(42));
+        let c = Rastrigin::sample(3, 50, &mut from_seed(43));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.into_iter().all(Rastrigin::in_bounds));
+        assert_eq!(
+            Rastrigin::latin_hypercube(3, 10, &mut from_seed(7)),
+            Rastrigin::latin_hypercube(3, 10, &mut from_seed(7))
+        );
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn check_latin_hypercube() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let samples = 20;
+        let points = Rastrigin::latin_hypercube(3, samples, &mut rng);
+        assert_eq!(points.len(), samples);
+        for j in 0..3 {
+            let mut occupied = vec![false; samples];
+            for point in points.iter() {
+                let stratum = (Rastrigin::to_unit_cube(point.clone())[j]*(samples as f64)) as usize;
+                assert!(!occupied[stratum]);
+                occupied[stratum] = true;
+            }
+        }
+        assert!(points.into_iter().all(Rastrigin::in_bounds));
+    }
+
+    #[cfg(feature = "qmc")]
+    #[test]
+    fn check_sobol() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        use crate::{Sphere, SingleObjective};
+
+        let (n, count) = (5, 1024);
+        let points = Rastrigin::sobol(n, count);
+        assert_eq!(points.len(), count);
+        assert_eq!(points[0], vec![Rastrigin::BOUNDS.0; n]);
+        assert!(points.iter().all(|x| Rastrigin::in_bounds(x.clone())));
+
+        // The mean of each x_i^2 over the box is c^2 with c = 5.12/sqrt(3)
+        let exact = Sphere::f(vec![Rastrigin::BOUNDS.1/3.0f64.sqrt(); n]);
+        let mean = |xs: Vec<Vec<f64>>| xs.into_iter().map(Sphere::f).sum::<f64>()/(count as f64);
+        let mut rng = StdRng::seed_from_u64(0);
+        let uniform = (0..count)
+            .map(|_| (0..n).map(|_| rng.gen_range(Rastrigin::BOUNDS.0..Rastrigin::BOUNDS.1)).collect())
+            .collect();
+        let sobol_error = (mean(points) - exact).abs();
+        let uniform_error = (mean(uniform) - exact).abs();
+        assert!(sobol_error < 0.1*uniform_error);
+    }
+}
+
+#[cfg(test)]
+mod dyn_bounded_tests {
+    use super::{Rastrigin, DynBounded, Bounded};
+
+    #[test]
+    fn check_blanket_bounds() {
+        assert_eq!(<Rastrigin as DynBounded>::bounds(2), Rastrigin::BOUNDS);
+        assert_eq!(<Rastrigin as DynBounded>::bounds(100), Rastrigin::BOUNDS);
+    }
+}
+
+#[cfg(test)]
+mod properties_tests {
+    use super::{Sphere, Rastrigin, Rosenbrock, Ackley, Zakharov, BentCigar, Discus, Ellipsoid, Properties, NDimensional};
+
+    const _: () = assert!(Sphere::SEPARABLE && Sphere::DIFFERENTIABLE && !Sphere::MULTIMODAL);
+    const _: () = assert!(Rastrigin::SEPARABLE && Rastrigin::DIFFERENTIABLE && Rastrigin::MULTIMODAL);
+    const _: () = assert!(!Rosenbrock::SEPARABLE && Rosenbrock::DIFFERENTIABLE && !Rosenbrock::MULTIMODAL);
+    const _: () = assert!(!Ackley::SEPARABLE && Ackley::MULTIMODAL);
+
+    #[test]
+    fn check_local_minima() {
+        assert_eq!(Sphere::num_local_minima(Sphere::HIGH_D), Some(1));
+        assert_eq!(Zakharov::num_local_minima(Zakharov::HIGH_D), Some(1));
+        assert_eq!(Rastrigin::num_local_minima(1), Some(11));
+        assert_eq!(Rastrigin::num_local_minima(3), Some(1331));
+        assert_eq!(Rastrigin::num_local_minima(Rastrigin::HIGH_D), None);
+        assert_eq!(Ackley::num_local_minima(2), None);
+    }
+
+    #[test]
+    fn check_condition_number() {
+        assert_eq!(Sphere::condition_number(Sphere::HIGH_D), Some(1.0));
+        assert_eq!(BentCigar::condition_number(BentCigar::HIGH_D), Some(1e6));
+        assert_eq!(Discus::condition_number(Discus::HIGH_D), Some(1e6));
+        assert_eq!(Ellipsoid::condition_number(Ellipsoid::HIGH_D), Some(1e6));
+        assert_eq!(Ellipsoid::condition_number(1), Some(1.0));
+        assert_eq!(Rosenbrock::condition_number(2), None);
+    }
 }
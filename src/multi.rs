@@ -1,6 +1,336 @@
 //! This module contains multi-objective functions
 
-use crate::{FixedDimensional, NDimensional, UnConstrained, Constrained, MultiObjective, Bounded};
+use crate::{FixedDimensional, NDimensional, UnConstrained, Constrained, MultiObjective, Bounded, BoxBounded, ParetoFront};
+use crate::registry::DynMultiObjective;
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+
+/// This function returns true when `a` Pareto-dominates `b`, assuming all objectives are minimized.
+///
+/// That is, `a` is no worse than `b` in every objective, and strictly better in at least one.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    let mut strictly_better = false;
+    for (ai, bi) in a.iter().zip(b.iter()) {
+        if ai > bi {
+            return false;
+        }
+        if ai < bi {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// This function returns the indices of the non-dominated (Pareto-optimal) subset of `points`.
+///
+/// Duplicate points do not dominate each other, so all copies of a non-dominated point are kept.
+pub fn non_dominated_indices(points: &[Vec<f64>]) -> Vec<usize> {
+    let mut indices: Vec<usize> = Vec::new();
+    for (i, a) in points.iter().enumerate() {
+        if !points.iter().any(|b| dominates(b, a)) {
+            indices.push(i);
+        }
+    }
+    indices
+}
+
+/// This function computes the hypervolume indicator of `front` relative to the point `reference`.
+///
+/// The hypervolume is the measure of the objective space dominated by the front and bounded by the
+/// reference point, assuming all objectives are minimized. Points that do not strictly dominate the
+/// reference point contribute nothing, and points with non-finite objective values are ignored. The volume is computed by slicing along the last objective
+/// (the HSO approach), which is exact and efficient for 2 and 3 objectives.
+pub fn hypervolume(front: &[Vec<f64>], reference: &[f64]) -> f64 {
+    let mut points: Vec<Vec<f64>> = Vec::new();
+    for point in front {
+        if point.iter().zip(reference.iter()).all(|(pi, ri)| pi.is_finite() && pi < ri) {
+            points.push(point.clone());
+        }
+    }
+    let points: Vec<Vec<f64>> = non_dominated_indices(&points).iter().map(|&i| points[i].clone()).collect();
+    hypervolume_slice(points, reference)
+}
+
+/// This function recursively computes the hypervolume of a set of points that all lie within the
+/// reference point
+fn hypervolume_slice(mut points: Vec<Vec<f64>>, reference: &[f64]) -> f64 {
+    let m = reference.len();
+    if points.is_empty() || m == 0 {
+        return 0.0;
+    }
+    points.sort_by(|a, b| a[m - 1].total_cmp(&b[m - 1]));
+    if m == 1 {
+        return reference[0] - points[0][0];
+    }
+    if m == 2 {
+        let mut volume = 0.0;
+        let mut previous = reference[0];
+        for point in points.iter() {
+            if point[0] < previous {
+                volume += (previous - point[0])*(reference[1] - point[1]);
+                previous = point[0];
+            }
+        }
+        return volume;
+    }
+    let mut volume = 0.0;
+    for i in 0..points.len() {
+        let next = if i + 1 < points.len() { points[i + 1][m - 1] } else { reference[m - 1] };
+        let depth = next - points[i][m - 1];
+        if depth > 0.0 {
+            let projection: Vec<Vec<f64>> = points[..=i].iter().map(|p| p[..m - 1].to_vec()).collect();
+            volume += depth*hypervolume_slice(projection, &reference[..m - 1]);
+        }
+    }
+    volume
+}
+
+/// This function computes the generational distance of `approx` from the true front `truth`.
+///
+/// The generational distance is the mean Euclidean distance from each point of the approximation to
+/// its nearest neighbor on the true front, and measures convergence. An empty approximation has a
+/// generational distance of zero, and the function panics if the true front is empty.
+pub fn generational_distance(approx: &[Vec<f64>], truth: &[Vec<f64>]) -> f64 {
+    if approx.is_empty() {
+        return 0.0;
+    }
+    if truth.is_empty() {
+        panic!("The generational distance of an approximation with {} points was requested from an empty true front.", approx.len());
+    }
+    mean_nearest_distance(approx, truth)
+}
+
+/// This function computes the inverted generational distance of `approx` from the true front `truth`.
+///
+/// The inverted generational distance is the mean Euclidean distance from each point of the true
+/// front to its nearest neighbor in the approximation, and measures both convergence and coverage.
+/// An empty true front has an inverted generational distance of zero, and the function panics if
+/// the approximation is empty.
+pub fn inverted_generational_distance(approx: &[Vec<f64>], truth: &[Vec<f64>]) -> f64 {
+    if truth.is_empty() {
+        return 0.0;
+    }
+    if approx.is_empty() {
+        panic!("The inverted generational distance of an empty approximation was requested from a true front with {} points.", truth.len());
+    }
+    mean_nearest_distance(truth, approx)
+}
+
+/// This function returns the mean Euclidean distance from each point of `from` to its nearest
+/// neighbor in `to`
+fn mean_nearest_distance(from: &[Vec<f64>], to: &[Vec<f64>]) -> f64 {
+    let mut total = 0.0;
+    for point in from {
+        total += nearest_distance(point, to);
+    }
+    total/(from.len() as f64)
+}
+
+/// This function computes the spacing of an approximation of the Pareto front.
+///
+/// The spacing is the standard deviation of the Euclidean distances between consecutive points,
+/// after sorting them by the first objective, so a lower spacing means a more uniform coverage of
+/// the front. Points with non-finite objective values are ignored, and fronts with fewer than two
+/// remaining points have a spacing of zero.
+pub fn spacing(front: &[Vec<f64>]) -> f64 {
+    let mut sorted: Vec<&Vec<f64>> = front.iter().filter(|point| point.iter().all(|pi| pi.is_finite())).collect();
+    if sorted.len() < 2 {
+        return 0.0;
+    }
+    sorted.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    let distances: Vec<f64> = sorted
+        .windows(2)
+        .map(|w| w[0].iter().zip(w[1].iter()).map(|(ai, bi)| (ai - bi).powi(2)).sum::<f64>().sqrt())
+        .collect();
+    let mean = distances.iter().sum::<f64>()/(distances.len() as f64);
+    let variance = distances.iter().map(|di| (di - mean).powi(2)).sum::<f64>()/(distances.len() as f64);
+    variance.sqrt()
+}
+
+/// This function returns the Euclidean distance from `point` to the closest element of `set`
+fn nearest_distance(point: &[f64], set: &[Vec<f64>]) -> f64 {
+    let mut nearest = f64::INFINITY;
+    for other in set {
+        let mut square_sum = 0.0;
+        for (pi, oi) in point.iter().zip(other.iter()) {
+            square_sum += (pi - oi).powi(2);
+        }
+        nearest = nearest.min(square_sum.sqrt());
+    }
+    nearest
+}
+
+#[cfg(test)]
+mod generational_distance_tests {
+    use super::{generational_distance, inverted_generational_distance, Zdt6, ParetoFront};
+
+    #[test]
+    fn check_equal() {
+        let truth = Zdt6::pareto_front(101);
+        assert_eq!(generational_distance(&truth, &truth), 0.0);
+        assert_eq!(inverted_generational_distance(&truth, &truth), 0.0);
+    }
+
+    #[test]
+    fn check_offset() {
+        let truth = vec![vec![0.0, 0.0], vec![10.0, 0.0], vec![20.0, 0.0]];
+        let approx: Vec<Vec<f64>> = truth.iter().map(|p| vec![p[0], p[1] + 0.5]).collect();
+        assert!((generational_distance(&approx, &truth) - 0.5).abs() < 1e-12);
+        assert!((inverted_generational_distance(&approx, &truth) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_coverage() {
+        let truth = vec![vec![0.0, 1.0], vec![0.5, 0.5], vec![1.0, 0.0]];
+        let approx = vec![vec![0.0, 1.0]];
+        assert_eq!(generational_distance(&approx, &truth), 0.0);
+        let expected = (0.5f64.sqrt() + 2.0f64.sqrt())/3.0;
+        assert!((inverted_generational_distance(&approx, &truth) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_empty() {
+        let front = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+        assert_eq!(generational_distance(&[], &front), 0.0);
+        assert_eq!(inverted_generational_distance(&front, &[]), 0.0);
+        assert_eq!(generational_distance(&[], &[]), 0.0);
+        assert_eq!(inverted_generational_distance(&[], &[]), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty true front")]
+    fn check_empty_truth() {
+        generational_distance(&[vec![0.0, 1.0]], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty approximation")]
+    fn check_empty_approx() {
+        inverted_generational_distance(&[], &[vec![0.0, 1.0]]);
+    }
+}
+
+#[cfg(test)]
+mod spacing_tests {
+    use super::spacing;
+
+    #[test]
+    fn check_uniform() {
+        let front: Vec<Vec<f64>> = (0..11).map(|i| vec![(i as f64)/10.0, 1.0 - (i as f64)/10.0]).collect();
+        assert!(spacing(&front) < 1e-12);
+    }
+
+    #[test]
+    fn check_clustered() {
+        let front = vec![vec![0.0, 1.0], vec![0.01, 0.99], vec![0.02, 0.98], vec![1.0, 0.0]];
+        assert!(spacing(&front) > 0.3);
+        let shuffled = vec![vec![1.0, 0.0], vec![0.02, 0.98], vec![0.0, 1.0], vec![0.01, 0.99]];
+        assert_eq!(spacing(&shuffled), spacing(&front));
+    }
+
+    #[test]
+    fn check_small() {
+        assert_eq!(spacing(&[]), 0.0);
+        assert_eq!(spacing(&[vec![0.0, 1.0]]), 0.0);
+    }
+
+    #[test]
+    fn check_non_finite() {
+        let front: Vec<Vec<f64>> = (0..11).map(|i| vec![(i as f64)/10.0, 1.0 - (i as f64)/10.0]).collect();
+        let mut polluted = front.clone();
+        polluted.push(vec![f64::NAN, 0.5]);
+        polluted.push(vec![0.5, f64::INFINITY]);
+        assert_eq!(spacing(&polluted), spacing(&front));
+        assert_eq!(spacing(&[vec![f64::NAN, 0.0], vec![0.0, 1.0]]), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod hypervolume_tests {
+    use super::hypervolume;
+
+    #[test]
+    fn check_single_point() {
+        assert!((hypervolume(&[vec![0.0, 0.0]], &[1.0, 1.0]) - 1.0).abs() < 1e-12);
+        assert!((hypervolume(&[vec![0.5, 0.25]], &[1.0, 1.0]) - 0.375).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_two_objectives() {
+        let front = vec![vec![1.0, 2.0], vec![2.0, 1.0]];
+        assert!((hypervolume(&front, &[3.0, 3.0]) - 3.0).abs() < 1e-12);
+        let front = vec![vec![0.0, 1.0], vec![0.25, 0.5], vec![0.5, 0.25], vec![1.0, 0.0]];
+        assert!((hypervolume(&front, &[1.1, 1.1]) - 0.71).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_dominated_and_outside() {
+        let front = vec![vec![1.0, 2.0], vec![2.0, 1.0], vec![2.5, 2.5], vec![4.0, 0.0], vec![1.0, 2.0]];
+        assert!((hypervolume(&front, &[3.0, 3.0]) - 3.0).abs() < 1e-12);
+        assert_eq!(hypervolume(&[vec![4.0, 4.0]], &[3.0, 3.0]), 0.0);
+        assert_eq!(hypervolume(&[], &[3.0, 3.0]), 0.0);
+    }
+
+    #[test]
+    fn check_non_finite() {
+        let front = vec![vec![1.0, 2.0], vec![f64::NAN, 0.5], vec![2.0, 1.0], vec![f64::NEG_INFINITY, 1.0]];
+        assert!((hypervolume(&front, &[3.0, 3.0]) - 3.0).abs() < 1e-12);
+        let front = vec![vec![0.0, 0.0, f64::NAN], vec![0.5, 0.5, 0.0]];
+        assert!((hypervolume(&front, &[1.0, 1.0, 1.0]) - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_three_objectives() {
+        assert!((hypervolume(&[vec![0.0, 0.0, 0.0]], &[1.0, 1.0, 1.0]) - 1.0).abs() < 1e-12);
+        let front = vec![vec![0.0, 0.0, 0.5], vec![0.5, 0.5, 0.0]];
+        assert!((hypervolume(&front, &[1.0, 1.0, 1.0]) - 0.625).abs() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod dominance_tests {
+    use super::{dominates, non_dominated_indices};
+
+    #[test]
+    fn check_dominates() {
+        assert!(dominates(&[1.0, 1.0], &[2.0, 2.0]));
+        assert!(dominates(&[1.0, 2.0], &[2.0, 2.0]));
+        assert!(!dominates(&[2.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[1.0, 3.0], &[2.0, 2.0]));
+    }
+
+    #[test]
+    fn check_ties() {
+        assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn check_non_dominated() {
+        let points = vec![
+            vec![1.0, 4.0],
+            vec![2.0, 2.0],
+            vec![3.0, 3.0],
+            vec![4.0, 1.0],
+            vec![2.0, 5.0],
+        ];
+        assert_eq!(non_dominated_indices(&points), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn check_duplicates() {
+        let points = vec![
+            vec![1.0, 2.0],
+            vec![1.0, 2.0],
+            vec![2.0, 2.0],
+        ];
+        assert_eq!(non_dominated_indices(&points), vec![0, 1]);
+    }
+
+    #[test]
+    fn check_empty() {
+        assert!(non_dominated_indices(&[]).is_empty());
+    }
+}
 
 /// This is the Chankong-Haimes function.
 ///
@@ -40,7 +370,7 @@ impl MultiObjective for ChankongHaimes {
     fn f(x: Vec<f64>) -> Vec<f64> {
         Self::check_input(x.clone());
         let mut fx: Vec<f64> = vec![0.0; Self::NF];
-        fx[0] = 2.0 + (x[0] - 2.0).powi(2) - (x[1] - 1.0).powi(2);
+        fx[0] = 2.0 + (x[0] - 2.0).powi(2) + (x[1] - 1.0).powi(2);
         fx[1] = 9.0*x[0] - (x[1] - 1.0).powi(2);
         fx
     }
@@ -49,23 +379,30 @@ impl MultiObjective for ChankongHaimes {
 #[cfg(test)]
 mod chankong_haimes_tests {
     use super::{ChankongHaimes as F, MultiObjective, Constrained, FixedDimensional};
+    use crate::ConstrainedMultiObjective;
 
     #[test]
     fn check_zero() {
         let x = vec![0.0; F::D];
-        F::f(x.clone());
-        F::equality_constraints(x.clone());
-        F::inequality_constraints(x);
-        assert!(true);
+        F::check_objectives_at(x.clone(), &[2.0 + 4.0 + 1.0, -1.0], 1e-12);
+        assert!(F::equality_constraints(x.clone()).is_empty());
+        assert_eq!(F::inequality_constraints(x), vec![-225.0, 10.0]);
     }
 
     #[test]
     fn check_one() {
-        let x = vec![0.0; F::D];
-        F::f(x.clone());
-        F::equality_constraints(x.clone());
-        F::inequality_constraints(x);
-        assert!(true);
+        let x = vec![1.0; F::D];
+        F::check_objectives_at(x.clone(), &[3.0, 9.0], 1e-12);
+        assert_eq!(F::inequality_constraints(x), vec![-223.0, 8.0]);
+    }
+
+    #[test]
+    fn check_full() {
+        let x = vec![-3.5, 4.25];
+        let (objectives, equality, inequality) = F::evaluate_full(x.clone());
+        assert_eq!(objectives, F::f(x.clone()));
+        assert_eq!(equality, F::h(x.clone()));
+        assert_eq!(inequality, F::g(x));
     }
 }
 
@@ -111,16 +448,22 @@ mod flemingfonseca_tests {
 
     #[test]
     fn check_zero() {
-        F::f(vec![0.0; F::LOW_D]);
-        F::f(vec![0.0; F::HIGH_D]);
-        assert!(true);
+        let expected = 1.0 - (-1.0f64).exp();
+        F::check_objectives_at(vec![0.0; F::LOW_D], &[expected, expected], 1e-12);
+        F::check_objectives_at(vec![0.0; F::HIGH_D], &[expected, expected], 1e-12);
     }
 
     #[test]
     fn check_one() {
-        F::f(vec![1.0; F::LOW_D]);
-        F::f(vec![1.0; F::HIGH_D]);
-        assert!(true);
+        F::check_objectives_at(vec![1.0; F::LOW_D], &[0.15766111987646092, 0.9970572981549862], 1e-12);
+        F::check_objectives_at(vec![1.0; F::HIGH_D], &[1.0, 1.0], 1e-12);
+        F::check_objectives_at(vec![0.05; F::HIGH_D], &[0.15804609334480435, 0.9189727686313157], 1e-12);
+    }
+
+    #[test]
+    fn check_front_ends() {
+        let x = vec![1.0/(F::LOW_D as f64).sqrt(); F::LOW_D];
+        F::check_objectives_at(x, &[0.0, 1.0 - (-4.0f64).exp()], 1e-12);
     }
 }
 
@@ -130,7 +473,6 @@ mod flemingfonseca_tests {
 /// This function is specifically 2 dimensional, and has a Pareto fron that looks like this:
 ///
 /// ![](https://upload.wikimedia.org/wikipedia/commons/thumb/f/f2/Viennet_function.pdf/page1-796px-Viennet_function.pdf.jpg)
-
 pub struct Viennet {}
 
 impl UnConstrained for Viennet {}
@@ -164,15 +506,712 @@ mod viennet_tests {
 
     #[test]
     fn check_zero() {
-        let x = vec![0.0; F::D];
-        F::f(x.clone());
-        assert!(true);
+        F::check_objectives_at(vec![0.0; F::D], &[0.0, 17.037037037037038, -0.1], 1e-12);
     }
 
     #[test]
     fn check_one() {
-        let x = vec![0.0; F::D];
-        F::f(x.clone());
-        assert!(true);
+        F::check_objectives_at(vec![1.0; F::D], &[1.9092974268256817, 18.162037037037038, 0.18446452177305933], 1e-12);
+    }
+}
+
+/// This is the ZDT6 function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// Although the function accepts a vector with an arbitrary number of inputs, the Pareto front is
+/// always the curve `f2 = 1 - f1^2`. The front is non-convex, and solutions are distributed
+/// non-uniformly along it, becoming sparse as `f1` decreases.
+pub struct Zdt6 {}
+
+impl NDimensional for Zdt6 {
+    const MIN_D: usize = 2;
+}
+impl UnConstrained for Zdt6 {}
+
+impl Bounded for Zdt6 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl MultiObjective for Zdt6 {
+    const NF: usize = 2;
+
+    fn f(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(&x);
+        let mut fx: Vec<f64> = vec![0.0; Self::NF];
+        let n = x.len();
+        let mut sum: f64 = 0.0;
+        for xi in x.iter().skip(1) {
+            sum += xi;
+        }
+        let g = 1.0 + 9.0*(sum/((n - 1) as f64)).powf(0.25);
+        fx[0] = 1.0 - (-4.0*x[0]).exp()*(6.0*core::f64::consts::PI*x[0]).sin().powi(6);
+        fx[1] = g*(1.0 - (fx[0]/g).powi(2));
+        fx
+    }
+}
+
+impl ParetoFront for Zdt6 {
+    fn pareto_front(num_points: usize) -> Vec<Vec<f64>> {
+        let mut front: Vec<Vec<f64>> = Vec::with_capacity(num_points);
+        for i in 0..num_points {
+            let x1 = (i as f64)/((num_points.max(2) - 1) as f64);
+            let f1 = 1.0 - (-4.0*x1).exp()*(6.0*core::f64::consts::PI*x1).sin().powi(6);
+            front.push(vec![f1, 1.0 - f1.powi(2)]);
+        }
+        front
+    }
+
+    /// The first objective is smallest at the first peak of `exp(-4 x) sin^6(6 pi x)`
+    fn ideal_point() -> Vec<f64> {
+        vec![0.28077531881536966, 0.0]
+    }
+
+    fn nadir_point() -> Vec<f64> {
+        vec![1.0, 0.9211652203441275]
+    }
+}
+
+#[cfg(test)]
+mod zdt6_tests {
+    use super::{Zdt6 as F, NDimensional, MultiObjective, ParetoFront};
+
+    #[test]
+    fn check_zero() {
+        F::check_objectives_at(vec![0.0; F::LOW_D], &[1.0, 0.0], 1e-12);
+        F::check_objectives_at(vec![0.0; F::HIGH_D], &[1.0, 0.0], 1e-12);
+    }
+
+    #[test]
+    fn check_one() {
+        F::check_objectives_at(vec![1.0; F::LOW_D], &[1.0, 9.9], 1e-12);
+        F::check_objectives_at(vec![1.0; F::HIGH_D], &[1.0, 9.9], 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn check_one_d() {
+        F::f(vec![0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn check_empty() {
+        F::f(vec![]);
+    }
+
+    #[test]
+    fn front_matches_f() {
+        for (i, point) in F::pareto_front(101).iter().enumerate() {
+            let mut x = vec![0.0; F::LOW_D];
+            x[0] = (i as f64)/100.0;
+            let fx = F::f(x);
+            assert!((point[0] - fx[0]).abs() < 1e-12);
+            assert!((point[1] - fx[1]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn front_within_ideal_and_nadir() {
+        let (ideal, nadir) = (F::ideal_point(), F::nadir_point());
+        for point in F::pareto_front(1001) {
+            for i in 0..F::NF {
+                assert!((ideal[i] <= point[i]) && (point[i] <= nadir[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn front_non_uniform() {
+        let mut f1: Vec<f64> = F::pareto_front(1001).iter().map(|p| p[0]).collect();
+        f1.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let gaps: Vec<f64> = f1.windows(2).map(|w| w[1] - w[0]).collect();
+        let low_gap = gaps[..100].iter().cloned().fold(0.0, f64::max);
+        let high_gap = gaps[gaps.len() - 100..].iter().cloned().fold(0.0, f64::max);
+        assert!(low_gap > 100.0*high_gap);
+    }
+}
+
+
+/// This is the Binh-Korn function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, with different bounds on each variable and two
+/// inequality constraints.
+pub struct BinhKorn {}
+
+impl FixedDimensional for BinhKorn {
+    const D: usize = 2;
+}
+
+impl BoxBounded for BinhKorn {
+    const BOUNDS: &'static [(f64, f64)] = &[(0.0, 5.0), (0.0, 3.0)];
+}
+
+impl Constrained for BinhKorn {
+    const NH: usize = 0;
+    const NG: usize = 2;
+
+    fn equality_constraints(_x: Vec<f64>) -> Vec<f64> {
+        vec![0.0; Self::NH]
+    }
+
+    fn inequality_constraints(x: Vec<f64>) -> Vec<f64> {
+        let mut fx: Vec<f64> = vec![0.0; Self::NG];
+        fx[0] = (x[0] - 5.0).powi(2) + x[1].powi(2) - 25.0;
+        fx[1] = 7.7 - (x[0] - 8.0).powi(2) - (x[1] + 3.0).powi(2);
+        fx
+    }
+}
+
+impl MultiObjective for BinhKorn {
+    const NF: usize = 2;
+
+    fn f(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(x.clone());
+        let mut fx: Vec<f64> = vec![0.0; Self::NF];
+        fx[0] = 4.0*x[0].powi(2) + 4.0*x[1].powi(2);
+        fx[1] = (x[0] - 5.0).powi(2) + (x[1] - 5.0).powi(2);
+        fx
+    }
+}
+
+#[cfg(test)]
+mod binh_korn_tests {
+    use super::{BinhKorn as F, MultiObjective, Constrained, BoxBounded};
+
+    #[test]
+    fn check_objectives() {
+        assert_eq!(F::f(vec![0.0, 0.0]), vec![0.0, 50.0]);
+        assert_eq!(F::f(vec![1.0, 1.0]), vec![8.0, 32.0]);
+        assert_eq!(F::f(vec![5.0, 3.0]), vec![136.0, 4.0]);
+    }
+
+    #[test]
+    fn check_feasible() {
+        for x in [vec![1.0, 1.0], vec![5.0, 3.0], vec![2.5, 1.5]] {
+            assert!(F::in_bounds(x.clone()));
+            for gi in F::inequality_constraints(x) {
+                assert!(gi <= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn check_infeasible() {
+        assert!(F::inequality_constraints(vec![0.0, 3.0])[0] > 0.0);
+        assert!(F::inequality_constraints(vec![8.0, -3.0])[1] > 0.0);
+        assert!(!F::in_bounds(vec![6.0, 1.0]));
+        assert!(!F::in_bounds(vec![1.0, 4.0]));
+    }
+}
+
+/// This is the Poloni function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 2 dimensional, and has a disconnected Pareto front.
+pub struct Poloni {}
+
+impl UnConstrained for Poloni {}
+
+impl FixedDimensional for Poloni {
+    const D: usize = 2;
+}
+
+impl Bounded for Poloni {
+    const BOUNDS: (f64, f64) = (-core::f64::consts::PI, core::f64::consts::PI);
+}
+
+impl MultiObjective for Poloni {
+    const NF: usize = 2;
+
+    fn f(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(x.clone());
+        let mut fx: Vec<f64> = vec![0.0; Self::NF];
+        let a1 = 0.5*1.0f64.sin() - 2.0*1.0f64.cos() + 2.0f64.sin() - 1.5*2.0f64.cos();
+        let a2 = 1.5*1.0f64.sin() - 1.0f64.cos() + 2.0*2.0f64.sin() - 0.5*2.0f64.cos();
+        let b1 = 0.5*x[0].sin() - 2.0*x[0].cos() + x[1].sin() - 1.5*x[1].cos();
+        let b2 = 1.5*x[0].sin() - x[0].cos() + 2.0*x[1].sin() - 0.5*x[1].cos();
+        fx[0] = 1.0 + (a1 - b1).powi(2) + (a2 - b2).powi(2);
+        fx[1] = (x[0] + 3.0).powi(2) + (x[1] + 1.0).powi(2);
+        fx
+    }
+}
+
+#[cfg(test)]
+mod poloni_tests {
+    use super::{Poloni as F, MultiObjective};
+
+    #[test]
+    fn check_zero() {
+        let fx = F::f(vec![0.0, 0.0]);
+        assert!((fx[0] - 38.17916955233353).abs() < 1e-12);
+        assert!((fx[1] - 10.0).abs() < 1e-12);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn check_interior() {
+        for x in [vec![1.0, 2.0], vec![-1.5, 0.5], vec![3.0, -3.0], vec![-0.25, -2.75]] {
+            for fi in F::f(x) {
+                assert!(fi.is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn check_minimum_f1() {
+        let fx = F::f(vec![1.0, 2.0]);
+        assert!((fx[0] - 1.0).abs() < 1e-12);
+    }
+}
+
+/// This is the Schaffer N.1 function.
+///
+/// The function is borrowed from [here](https://en.wikipedia.org/wiki/Test_functions_for_optimization).
+/// This function is specifically 1 dimensional, and has a Pareto front for `x` between 0 and 2.
+pub struct SchafferN1 {}
+
+impl UnConstrained for SchafferN1 {}
+
+impl FixedDimensional for SchafferN1 {
+    const D: usize = 1;
+}
+
+impl Bounded for SchafferN1 {
+    const BOUNDS: (f64, f64) = (-1000.0, 1000.0);
+}
+
+impl MultiObjective for SchafferN1 {
+    const NF: usize = 2;
+
+    fn f(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(x.clone());
+        let mut fx: Vec<f64> = vec![0.0; Self::NF];
+        fx[0] = x[0].powi(2);
+        fx[1] = (x[0] - 2.0).powi(2);
+        fx
+    }
+}
+
+impl ParetoFront for SchafferN1 {
+    fn pareto_front(num_points: usize) -> Vec<Vec<f64>> {
+        let mut front: Vec<Vec<f64>> = Vec::with_capacity(num_points);
+        for i in 0..num_points {
+            let x = 2.0*(i as f64)/((num_points.max(2) - 1) as f64);
+            front.push(Self::f(vec![x]));
+        }
+        front
+    }
+
+    fn ideal_point() -> Vec<f64> {
+        vec![0.0, 0.0]
+    }
+
+    fn nadir_point() -> Vec<f64> {
+        vec![4.0, 4.0]
+    }
+}
+
+#[cfg(test)]
+mod schaffer_n1_tests {
+    use super::{SchafferN1 as F, MultiObjective, ParetoFront, non_dominated_indices};
+
+    #[test]
+    fn check_zero() {
+        assert_eq!(F::f(vec![0.0]), vec![0.0, 4.0]);
+    }
+
+    #[test]
+    fn check_one() {
+        assert_eq!(F::f(vec![1.0]), vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn front_non_dominated() {
+        let front = F::pareto_front(51);
+        assert_eq!(non_dominated_indices(&front).len(), front.len());
+    }
+
+    #[test]
+    fn front_within_ideal_and_nadir() {
+        let (ideal, nadir) = (F::ideal_point(), F::nadir_point());
+        let front = F::pareto_front(51);
+        for point in front.iter() {
+            for i in 0..F::NF {
+                assert!((ideal[i] <= point[i]) && (point[i] <= nadir[i]));
+            }
+        }
+        assert_eq!(front[0], vec![ideal[0], nadir[1]]);
+        assert_eq!(front[50], vec![nadir[0], ideal[1]]);
+    }
+}
+
+/// This function returns `num_points` well-spread points in the `d`-dimensional unit cube.
+///
+/// The points follow the additive recurrence based on the generalized golden ratio, which is used
+/// to sample the position variables of the scalable DTLZ problems.
+fn unit_cube_points(num_points: usize, d: usize) -> Vec<Vec<f64>> {
+    let mut phi = 2.0f64;
+    for _ in 0..64 {
+        phi = (1.0 + phi).powf(1.0/((d + 1) as f64));
+    }
+    let alpha: Vec<f64> = (1..=d).map(|i| (1.0/phi).powi(i as i32)).collect();
+    (0..num_points)
+        .map(|p| alpha.iter().map(|a| (0.5 + a*((p + 1) as f64)) % 1.0).collect())
+        .collect()
+}
+
+/// This is the DTLZ1 function.
+///
+/// The function is borrowed from Deb, Thiele, Laumanns and Zitzler, "Scalable Multi-Objective
+/// Optimization Test Problems" (2002). The number of objectives `m` is chosen when the problem is
+/// constructed, and the function accepts
+/// `n = m + k - 1` inputs, where `k = 5` is the number of distance variables. The Pareto front is
+/// the linear hyperplane where the objectives sum to `0.5`, and the problem has `11^k - 1` local
+/// fronts.
+pub struct Dtlz1 {
+    m: usize,
+    k: usize,
+}
+
+impl UnConstrained for Dtlz1 {}
+
+impl Bounded for Dtlz1 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl Dtlz1 {
+    /// This function constructs the problem with `m` objectives
+    pub fn new(m: usize) -> Self {
+        assert!(m >= 2, "DTLZ1 requires at least 2 objectives, but {} were requested", m);
+        Dtlz1 { m, k: 5 }
+    }
+
+    /// Function for evaluating the set of objective functions
+    pub fn f(&self, x: Vec<f64>) -> Vec<f64> {
+        assert_eq!(x.len(), self.dim(), "DTLZ1 with {} objectives requires {} inputs", self.m, self.dim());
+        let mut g = self.k as f64;
+        for xi in x.iter().skip(self.m - 1) {
+            g += (xi - 0.5).powi(2) - (20.0*core::f64::consts::PI*(xi - 0.5)).cos();
+        }
+        g *= 100.0;
+        let mut fx: Vec<f64> = vec![0.5*(1.0 + g); self.m];
+        for (j, fj) in fx.iter_mut().enumerate() {
+            for xi in x.iter().take(self.m - 1 - j) {
+                *fj *= xi;
+            }
+            if j > 0 {
+                *fj *= 1.0 - x[self.m - 1 - j];
+            }
+        }
+        fx
+    }
+
+    /// This function returns a sampling of the true Pareto front, with one objective vector per point
+    pub fn pareto_front(&self, num_points: usize) -> Vec<Vec<f64>> {
+        unit_cube_points(num_points, self.m - 1)
+            .into_iter()
+            .map(|mut x| {
+                x.resize(self.dim(), 0.5);
+                self.f(x)
+            })
+            .collect()
+    }
+}
+
+impl DynMultiObjective for Dtlz1 {
+    fn evaluate(&self, x: &[f64]) -> Vec<f64> {
+        self.f(x.to_vec())
+    }
+
+    fn num_objectives(&self) -> usize {
+        self.m
+    }
+
+    fn dim(&self) -> usize {
+        self.m + self.k - 1
+    }
+}
+
+#[cfg(test)]
+mod dtlz1_tests {
+    use super::{Dtlz1, DynMultiObjective, non_dominated_indices};
+
+    #[test]
+    fn check_dim() {
+        assert_eq!(Dtlz1::new(2).dim(), 6);
+        assert_eq!(Dtlz1::new(3).dim(), 7);
+        assert_eq!(Dtlz1::new(3).f(vec![0.5; 7]).len(), 3);
+    }
+
+    #[test]
+    fn front_on_hyperplane() {
+        for m in [2, 3, 5] {
+            let problem = Dtlz1::new(m);
+            for point in problem.pareto_front(100) {
+                assert_eq!(point.len(), m);
+                assert!((point.iter().sum::<f64>() - 0.5).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn front_non_dominated() {
+        let front = Dtlz1::new(3).pareto_front(100);
+        assert_eq!(non_dominated_indices(&front).len(), front.len());
+    }
+
+    #[test]
+    fn check_off_front() {
+        let problem = Dtlz1::new(3);
+        let fx = problem.f(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert!(fx.iter().sum::<f64>() > 0.5);
+    }
+}
+
+/// This is the DTLZ2 function.
+///
+/// The function is borrowed from Deb, Thiele, Laumanns and Zitzler, "Scalable Multi-Objective
+/// Optimization Test Problems" (2002). The number of objectives `m` is chosen when the problem is
+/// constructed, and the function accepts `n = m + k - 1` inputs, where `k = 10` is the number of
+/// distance variables. The Pareto front is the positive orthant of the unit hypersphere.
+pub struct Dtlz2 {
+    m: usize,
+    k: usize,
+}
+
+impl UnConstrained for Dtlz2 {}
+
+impl Bounded for Dtlz2 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl Dtlz2 {
+    /// This function constructs the problem with `m` objectives
+    pub fn new(m: usize) -> Self {
+        assert!(m >= 2, "DTLZ2 requires at least 2 objectives, but {} were requested", m);
+        Dtlz2 { m, k: 10 }
+    }
+
+    /// Function for evaluating the set of objective functions
+    pub fn f(&self, x: Vec<f64>) -> Vec<f64> {
+        assert_eq!(x.len(), self.dim(), "DTLZ2 with {} objectives requires {} inputs", self.m, self.dim());
+        let mut g = 0.0;
+        for xi in x.iter().skip(self.m - 1) {
+            g += (xi - 0.5).powi(2);
+        }
+        let half_pi = core::f64::consts::FRAC_PI_2;
+        let mut fx: Vec<f64> = vec![1.0 + g; self.m];
+        for (j, fj) in fx.iter_mut().enumerate() {
+            for xi in x.iter().take(self.m - 1 - j) {
+                *fj *= (half_pi*xi).cos();
+            }
+            if j > 0 {
+                *fj *= (half_pi*x[self.m - 1 - j]).sin();
+            }
+        }
+        fx
+    }
+
+    /// This function returns a sampling of the true Pareto front, with one objective vector per point
+    pub fn pareto_front(&self, num_points: usize) -> Vec<Vec<f64>> {
+        unit_cube_points(num_points, self.m - 1)
+            .into_iter()
+            .map(|mut x| {
+                x.resize(self.dim(), 0.5);
+                self.f(x)
+            })
+            .collect()
+    }
+}
+
+impl DynMultiObjective for Dtlz2 {
+    fn evaluate(&self, x: &[f64]) -> Vec<f64> {
+        self.f(x.to_vec())
+    }
+
+    fn num_objectives(&self) -> usize {
+        self.m
+    }
+
+    fn dim(&self) -> usize {
+        self.m + self.k - 1
+    }
+}
+
+#[cfg(test)]
+mod dtlz2_tests {
+    use super::{Dtlz2, DynMultiObjective, non_dominated_indices};
+
+    #[test]
+    fn check_dim() {
+        assert_eq!(Dtlz2::new(2).dim(), 11);
+        assert_eq!(Dtlz2::new(3).f(vec![0.5; 12]).len(), 3);
+    }
+
+    #[test]
+    fn front_on_sphere() {
+        for m in [2, 3, 5] {
+            let problem = Dtlz2::new(m);
+            for point in problem.pareto_front(100) {
+                assert_eq!(point.len(), m);
+                assert!((point.iter().map(|fi| fi.powi(2)).sum::<f64>() - 1.0).abs() < 1e-12);
+                assert!(point.iter().all(|&fi| fi >= 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn front_non_dominated() {
+        let front = Dtlz2::new(3).pareto_front(100);
+        assert_eq!(non_dominated_indices(&front).len(), front.len());
+    }
+
+    #[test]
+    fn check_configurations() {
+        let problems: Vec<Box<dyn DynMultiObjective>> = vec![Box::new(Dtlz2::new(2)), Box::new(Dtlz2::new(4))];
+        for problem in problems.iter() {
+            let fx = problem.evaluate(&vec![0.5; problem.dim()]);
+            assert_eq!(fx.len(), problem.num_objectives());
+            assert!((fx.iter().map(|fi| fi.powi(2)).sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(problems[0].dim(), 11);
+        assert_eq!(problems[1].dim(), 13);
+    }
+}
+
+/// These are the intervals of each of the first `m - 1` objectives of DTLZ7 that lie on the
+/// Pareto front, between which the front is disconnected
+const DTLZ7_REGIONS: [(f64, f64); 2] = [(0.0, 0.25141183608891715), (0.6316265307000613, 0.859400856644724)];
+
+/// This is the DTLZ7 function.
+///
+/// The function is borrowed from Deb, Thiele, Laumanns and Zitzler, "Scalable Multi-Objective
+/// Optimization Test Problems" (2002). The number of objectives `m` is chosen when the problem is
+/// constructed, and the function accepts `n = m + k - 1` inputs, where `k = 20` is the number of
+/// distance variables. The Pareto front is split into `2^(m-1)` disconnected regions, which makes
+/// it a test of how well an optimizer preserves diversity.
+pub struct Dtlz7 {
+    m: usize,
+    k: usize,
+}
+
+impl UnConstrained for Dtlz7 {}
+
+impl Bounded for Dtlz7 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl Dtlz7 {
+    /// This function constructs the problem with `m` objectives
+    pub fn new(m: usize) -> Self {
+        assert!(m >= 2, "DTLZ7 requires at least 2 objectives, but {} were requested", m);
+        Dtlz7 { m, k: 20 }
+    }
+
+    /// Function for evaluating the set of objective functions
+    pub fn f(&self, x: Vec<f64>) -> Vec<f64> {
+        assert_eq!(x.len(), self.dim(), "DTLZ7 with {} objectives requires {} inputs", self.m, self.dim());
+        let mut g = 0.0;
+        for xi in x.iter().skip(self.m - 1) {
+            g += xi;
+        }
+        g = 1.0 + 9.0*g/(self.k as f64);
+        let mut fx: Vec<f64> = x.iter().take(self.m - 1).copied().collect();
+        let mut h = self.m as f64;
+        for fi in fx.iter() {
+            h -= fi/(1.0 + g)*(1.0 + (3.0*core::f64::consts::PI*fi).sin());
+        }
+        fx.push((1.0 + g)*h);
+        fx
+    }
+
+    /// This function returns a sampling of the true Pareto front, with one objective vector per
+    /// point, spread across all of its disconnected regions
+    pub fn pareto_front(&self, num_points: usize) -> Vec<Vec<f64>> {
+        let (first, second) = (DTLZ7_REGIONS[0], DTLZ7_REGIONS[1]);
+        let first_length = first.1 - first.0;
+        let length = first_length + second.1 - second.0;
+        unit_cube_points(num_points, self.m - 1)
+            .into_iter()
+            .map(|u| {
+                let mut x: Vec<f64> = u
+                    .iter()
+                    .map(|ui| {
+                        let s = ui*length;
+                        if s <= first_length {
+                            first.0 + s
+                        } else {
+                            second.0 + s - first_length
+                        }
+                    })
+                    .collect();
+                x.resize(self.dim(), 0.0);
+                self.f(x)
+            })
+            .collect()
+    }
+}
+
+impl DynMultiObjective for Dtlz7 {
+    fn evaluate(&self, x: &[f64]) -> Vec<f64> {
+        self.f(x.to_vec())
+    }
+
+    fn num_objectives(&self) -> usize {
+        self.m
+    }
+
+    fn dim(&self) -> usize {
+        self.m + self.k - 1
+    }
+}
+
+#[cfg(test)]
+mod dtlz7_tests {
+    use super::{Dtlz7, DynMultiObjective, non_dominated_indices, DTLZ7_REGIONS};
+
+    /// This function returns the index of the disconnected region that a front point lies in
+    fn region(point: &[f64]) -> usize {
+        point[..point.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(i, fi)| if *fi >= DTLZ7_REGIONS[1].0 { 1 << i } else { 0 })
+            .sum()
+    }
+
+    #[test]
+    fn check_dim() {
+        assert_eq!(Dtlz7::new(2).dim(), 21);
+        assert_eq!(Dtlz7::new(3).f(vec![0.5; 22]).len(), 3);
+    }
+
+    #[test]
+    fn check_regions() {
+        for (m, expected) in [(2, 2), (3, 4)] {
+            let front = Dtlz7::new(m).pareto_front(200);
+            let mut regions: Vec<usize> = front.iter().map(|point| region(point)).collect();
+            regions.sort_unstable();
+            regions.dedup();
+            assert_eq!(regions.len(), expected);
+        }
+    }
+
+    #[test]
+    fn front_non_dominated() {
+        for m in [2, 3] {
+            let front = Dtlz7::new(m).pareto_front(200);
+            assert_eq!(non_dominated_indices(&front).len(), front.len());
+        }
+    }
+
+    #[test]
+    fn check_off_front() {
+        let problem = Dtlz7::new(2);
+        let mut x = vec![0.5; problem.dim()];
+        let off = problem.evaluate(&x);
+        x[1..].iter_mut().for_each(|xi| *xi = 0.0);
+        let on = problem.evaluate(&x);
+        assert_eq!(off[0], on[0]);
+        assert!(off[1] > on[1]);
+    }
+}
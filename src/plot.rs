@@ -0,0 +1,90 @@
+//! This module contains helpers for exporting benchmark function landscapes for plotting
+
+use core::fmt::Write;
+use crate::{SingleObjective, Bounded, ParetoFront};
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+
+/// This function evaluates a bounded function on a `resolution x resolution` mesh that spans its
+/// bounds in 2D, returning one `(x, y, f)` triple per mesh point
+pub fn grid_2d<F: SingleObjective + Bounded>(resolution: usize) -> Vec<(f64, f64, f64)> {
+    let (lower, upper) = F::BOUNDS;
+    let step = (upper - lower)/((resolution.max(2) - 1) as f64);
+    let mut grid: Vec<(f64, f64, f64)> = Vec::with_capacity(resolution*resolution);
+    for j in 0..resolution {
+        let y = lower + step*(j as f64);
+        for i in 0..resolution {
+            let x = lower + step*(i as f64);
+            grid.push((x, y, F::f(vec![x, y])));
+        }
+    }
+    grid
+}
+
+/// This function formats a grid of `(x, y, f)` triples as CSV, with a header row
+pub fn to_csv(grid: &[(f64, f64, f64)]) -> String {
+    let mut csv = String::from("x,y,f\n");
+    for (x, y, f) in grid {
+        writeln!(csv, "{},{},{}", x, y, f).unwrap();
+    }
+    csv
+}
+
+/// This function formats a sampling of the true Pareto front of a function as CSV, with one
+/// objective vector per row and a header row of `f1,f2,...`
+pub fn export_front_csv<F: ParetoFront>(num_points: usize) -> String {
+    let front = F::pareto_front(num_points);
+    let nf = front.first().map_or(0, |point| point.len());
+    let mut csv = String::new();
+    for i in 1..=nf {
+        let separator = if i < nf { "," } else { "\n" };
+        write!(csv, "f{}{}", i, separator).unwrap();
+    }
+    for point in front {
+        for (i, fi) in point.iter().enumerate() {
+            let separator = if i + 1 < point.len() { "," } else { "\n" };
+            write!(csv, "{}{}", fi, separator).unwrap();
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod plot_tests {
+    use super::{grid_2d, to_csv, export_front_csv};
+    use crate::{Ackley, Zdt6, Bounded, SingleObjective, non_dominated_indices};
+
+    #[test]
+    fn check_size() {
+        assert_eq!(grid_2d::<Ackley>(17).len(), 17*17);
+    }
+
+    #[test]
+    fn check_corners() {
+        let (lower, upper) = Ackley::BOUNDS;
+        let grid = grid_2d::<Ackley>(11);
+        assert_eq!((grid[0].0, grid[0].1), (lower, lower));
+        assert!((grid[10].0 - upper).abs() < 1e-12 && grid[10].1 == lower);
+        assert!(grid[110].0 == lower && (grid[110].1 - upper).abs() < 1e-12);
+        assert!((grid[120].0 - upper).abs() < 1e-12 && (grid[120].1 - upper).abs() < 1e-12);
+        assert_eq!(grid[60].2, Ackley::f(vec![grid[60].0, grid[60].1]));
+    }
+
+    #[test]
+    fn check_csv() {
+        let csv = to_csv(&[(0.0, 1.0, 2.0), (-1.5, 0.5, 3.25)]);
+        assert_eq!(csv, "x,y,f\n0,1,2\n-1.5,0.5,3.25\n");
+    }
+
+    #[test]
+    fn check_front_csv() {
+        let csv = export_front_csv::<Zdt6>(50);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("f1,f2"));
+        let front: Vec<Vec<f64>> = lines
+            .map(|line| line.split(',').map(|fi| fi.parse().unwrap()).collect())
+            .collect();
+        assert_eq!(front.len(), 50);
+        assert_eq!(non_dominated_indices(&front).len(), front.len());
+    }
+}
@@ -0,0 +1,67 @@
+//! This module provides the allocation types and floating-point math that the standard library
+//! would otherwise supply, so that the crate can be built without `std`
+
+pub use alloc::{vec, vec::Vec, boxed::Box, string::String};
+
+/// This trait routes the floating-point methods used by the benchmark functions through `libm`.
+/// Not every method is needed by every combination of features.
+#[allow(dead_code)]
+pub trait Float {
+    /// Computes the square root
+    fn sqrt(self) -> Self;
+
+    /// Computes the exponential function
+    fn exp(self) -> Self;
+
+    /// Computes the natural logarithm
+    fn ln(self) -> Self;
+
+    /// Computes the sine
+    fn sin(self) -> Self;
+
+    /// Computes the cosine
+    fn cos(self) -> Self;
+
+    /// Raises to an integer power
+    fn powi(self, n: i32) -> Self;
+
+    /// Raises to a floating-point power
+    fn powf(self, n: Self) -> Self;
+
+    /// Rounds down to the nearest integer
+    fn floor(self) -> Self;
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+}
@@ -0,0 +1,556 @@
+//! This module contains a registry of benchmark functions, which allows them to be selected by
+//! name at runtime rather than by type at compile time
+
+use crate::{SingleObjective, MultiObjective, Domain, Properties, DynBounded, FixedDimensional};
+use crate::single::*;
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// This is an object-safe trait for single-objective benchmark functions, which allows them to be
+/// stored and evaluated without knowing their concrete type. It is implemented for every function
+/// implementing `SingleObjective` and `Domain`, and functions defined outside the crate can
+/// implement `Domain` with `impl_domain!`.
+pub trait DynSingleObjective {
+    /// Function for evaluating the objective function
+    fn f(&self, x: &[f64]) -> f64;
+
+    /// The global minimum of the function in `n` dimensions
+    fn minimum(&self, n: usize) -> f64;
+
+    /// This function returns the minimizer (argument that will return the global minimum)
+    fn minimizer(&self, n: usize) -> Vec<f64>;
+
+    /// The bounds of the canonical optimization problem in `n` dimensions
+    fn bounds(&self, n: usize) -> (f64, f64);
+
+    /// The bounds of each variable in `n` dimensions, which are the same for every variable unless
+    /// the function is box-bounded
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        vec![self.bounds(n); n]
+    }
+
+    /// The dimensionality required by the function, or `None` if any dimensionality is accepted
+    fn dim(&self) -> Option<usize>;
+}
+
+impl<T: SingleObjective + Domain> DynSingleObjective for T {
+    fn f(&self, x: &[f64]) -> f64 {
+        <T as SingleObjective>::f(x.to_vec())
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        <T as SingleObjective>::minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        <T as SingleObjective>::minimizer(n)
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        <T as DynBounded>::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        <T as Domain>::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        <T as Domain>::dim()
+    }
+}
+
+/// This is an object-safe trait for multi-objective benchmark functions, which allows problems
+/// whose number of objectives is only known at runtime to carry their configuration as fields
+pub trait DynMultiObjective {
+    /// Function for evaluating the set of objective functions
+    fn evaluate(&self, x: &[f64]) -> Vec<f64>;
+
+    /// The number of objectives
+    fn num_objectives(&self) -> usize;
+
+    /// The number of inputs the problem accepts
+    fn dim(&self) -> usize;
+}
+
+impl<T: MultiObjective + FixedDimensional> DynMultiObjective for T {
+    fn evaluate(&self, x: &[f64]) -> Vec<f64> {
+        <T as MultiObjective>::f(x.to_vec())
+    }
+
+    fn num_objectives(&self) -> usize {
+        <T as MultiObjective>::num_objectives()
+    }
+
+    fn dim(&self) -> usize {
+        T::D
+    }
+}
+
+/// This is a record of the metadata of a registered single-objective function
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionInfo {
+    /// The name under which the function is registered
+    pub name: &'static str,
+
+    /// The dimensionality required by the function, or `None` if any dimensionality is accepted
+    pub dim: Option<usize>,
+
+    /// The dimensionality at which the bounds and the minimum are recorded, which is the required
+    /// dimensionality if there is one
+    pub reference_dim: usize,
+
+    /// The bounds of the canonical optimization problem at the reference dimensionality
+    pub bounds: (f64, f64),
+
+    /// The global minimum of the function at the reference dimensionality
+    pub minimum: f64,
+
+    /// Whether the function is separable in its variables
+    pub separable: bool,
+
+    /// Whether the function has more than one local minimum
+    pub multimodal: bool,
+}
+
+/// This is the dimensionality at which N-dimensional functions are recorded in the catalog
+const CATALOG_DIM: usize = 2;
+
+/// This is the dimensionality at which the difficulty of N-dimensional functions is scored
+const DIFFICULTY_DIM: usize = 10;
+
+/// This function scores the difficulty of a function from its `Properties`, adding a point for
+/// non-separability and for non-differentiability, two points for multimodality, and a point for
+/// every three orders of magnitude of the condition number
+fn difficulty<T: Properties + Domain>() -> f64 {
+    let n = T::dim().unwrap_or(DIFFICULTY_DIM);
+    let mut score = 0.0;
+    if !T::SEPARABLE {
+        score += 1.0;
+    }
+    if !T::DIFFERENTIABLE {
+        score += 1.0;
+    }
+    if T::MULTIMODAL {
+        score += 2.0;
+    }
+    score + T::condition_number(n).unwrap_or(1.0).ln()/(3.0*core::f64::consts::LN_10)
+}
+
+/// This macro implements `Domain` for each function before the semicolon, and builds the lookup by
+/// name over all functions, including the box-bounded ones after the semicolon, which implement
+/// `Domain` themselves
+macro_rules! register_single {
+    (@lookup $($name:expr => $t:ident,)*) => {
+        /// The names of all registered single-objective functions
+        #[cfg(test)]
+        const SINGLE_NAMES: &[&str] = &[$($name),*];
+
+        /// This function returns the initialization region of every registered single-objective
+        /// function, in `n` dimensions unless the function requires another dimensionality
+        #[cfg(test)]
+        fn init_regions(n: usize) -> Vec<Vec<(f64, f64)>> {
+            vec![$(<$t as crate::InitRegion>::init_region(<$t as Domain>::dim().unwrap_or(n))),*]
+        }
+
+        /// This function returns the single-objective function registered under `name`, if any
+        pub fn get_single(name: &str) -> Option<Box<dyn DynSingleObjective>> {
+            match name {
+                $($name => Some(Box::new($t {})),)*
+                _ => None,
+            }
+        }
+
+        /// This function returns every registered single-objective function, which is useful for
+        /// regression testing an optimizer against the whole suite. Every single-objective function
+        /// in the crate is registered except `XinSheYangN1`, whose coefficients are instance data
+        /// and which is built with `XinSheYangN1::new`.
+        pub fn all_single_objective() -> Vec<Box<dyn DynSingleObjective>> {
+            vec![$(Box::new($t {})),*]
+        }
+
+        /// This function returns the metadata of every registered single-objective function, which
+        /// covers every single-objective function in the crate except `XinSheYangN1`
+        pub fn function_catalog() -> Vec<FunctionInfo> {
+            vec![$(
+                FunctionInfo {
+                    name: $name,
+                    dim: <$t as Domain>::dim(),
+                    reference_dim: <$t as Domain>::dim().unwrap_or(CATALOG_DIM),
+                    bounds: <$t as DynBounded>::bounds(<$t as Domain>::dim().unwrap_or(CATALOG_DIM)),
+                    minimum: <$t as SingleObjective>::minimum(<$t as Domain>::dim().unwrap_or(CATALOG_DIM)),
+                    separable: <$t as Properties>::SEPARABLE,
+                    multimodal: <$t as Properties>::MULTIMODAL,
+                }
+            ),*]
+        }
+
+        /// This function returns the names of all registered single-objective functions, ordered
+        /// from easiest to hardest by a score over their `Properties`, which is useful for building
+        /// curriculum-style optimizer tests
+        pub fn single_by_difficulty() -> Vec<&'static str> {
+            let mut scored: Vec<(&'static str, f64)> = vec![$(($name, difficulty::<$t>())),*];
+            scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            scored.into_iter().map(|(name, _)| name).collect()
+        }
+    };
+    ($($name:expr => $t:ident,)* ; $($box_name:expr => $box_t:ident,)*) => {
+        crate::impl_domain!($($t),*);
+        register_single!(@lookup $($name => $t,)* $($box_name => $box_t,)*);
+    };
+}
+
+register_single! {
+    "sphere" => Sphere,
+    "rastrigin" => Rastrigin,
+    "rosenbrock" => Rosenbrock,
+    "ackley" => Ackley,
+    "matyas" => Matyas,
+    "griewank" => Griewank,
+    "ridge" => Ridge,
+    "zakharov" => Zakharov,
+    "salomon" => Salomon,
+    "rosenbrock_const1" => RosenbrockConst1,
+    "rosenbrock_const2" => RosenbrockConst2,
+    "happy_cat" => HappyCat,
+    "hgbat" => HgBat,
+    "bohachevsky_n1" => BohachevskyN1,
+    "bohachevsky_n2" => BohachevskyN2,
+    "bohachevsky_n3" => BohachevskyN3,
+    "hartmann3" => Hartmann3,
+    "hartmann6" => Hartmann6,
+    "colville" => Colville,
+    "perm" => Perm,
+    "powell_sum" => PowellSum,
+    "de_jong_n5" => DeJongN5,
+    "trigonometric" => Trigonometric,
+    "alpine_n1" => AlpineN1,
+    "alpine_n2" => AlpineN2,
+    "xin_she_yang_n2" => XinSheYangN2,
+    "xin_she_yang_n4" => XinSheYangN4,
+    "bent_cigar" => BentCigar,
+    "discus" => Discus,
+    "ellipsoid" => Ellipsoid,
+    "different_powers" => DifferentPowers,
+    "expanded_schaffer_f6" => ExpandedSchafferF6,
+    "penalized1" => Penalized1,
+    "penalized2" => Penalized2,
+    "step" => Step,
+    "schwefel222" => Schwefel222,
+    "schwefel12" => Schwefel12,
+    "schwefel221" => Schwefel221,
+    "sphere_ball_constrained" => SphereBallConstrained,
+    "michalewicz" => Michalewicz,
+    "csendes" => Csendes,
+    "deb_n1" => DebN1,
+    "periodic" => Periodic,
+    "pathological" => Pathological,
+    "brown" => Brown,
+    "chung_reynolds" => ChungReynolds,
+    "keane" => Keane,
+    "trid" => Trid,
+    "quartic" => Quartic,
+    ;
+    "g1" => G1,
+    "g6" => G6,
+}
+
+/// This is a description of one function in a benchmark suite
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuiteEntry {
+    /// The name under which the function is registered
+    pub function: String,
+
+    /// The dimensionality at which the function is run
+    pub dim: usize,
+
+    /// The shift applied to the function, if any
+    pub shift: Option<Vec<f64>>,
+
+    /// The seed of the run, if any, which is recorded for reproducibility but not used to build
+    /// the function
+    pub seed: Option<u64>,
+}
+
+/// This is a description of a benchmark suite, which records the functions of an experiment so
+/// that it can be reproduced
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuiteSpec {
+    /// The functions in the suite
+    pub entries: Vec<SuiteEntry>,
+}
+
+/// This is a registered function at the dimensionality, and with the shift, of a suite entry
+struct SuiteMember {
+    function: Box<dyn DynSingleObjective>,
+    dim: usize,
+    shift: Vec<f64>,
+}
+
+impl DynSingleObjective for SuiteMember {
+    fn f(&self, x: &[f64]) -> f64 {
+        if x.len() != self.dim {
+            panic!("A vector with size {} was used with a suite entry of dimensionality {}.", x.len(), self.dim);
+        }
+        let z: Vec<f64> = x.iter().zip(self.shift.iter()).map(|(xi, si)| xi - si).collect();
+        self.function.f(&z)
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        self.function.minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        self.function.minimizer(n).iter().zip(self.shift.iter()).map(|(xi, si)| xi + si).collect()
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        self.function.bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        self.function.box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        Some(self.dim)
+    }
+}
+
+/// This function builds the functions described by `spec` through the registry, shifting those
+/// with a shift
+pub fn build_suite(spec: &SuiteSpec) -> Vec<Box<dyn DynSingleObjective>> {
+    spec.entries
+        .iter()
+        .map(|entry| {
+            let function = match get_single(&entry.function) {
+                Some(function) => function,
+                None => panic!("No single-objective function is registered under the name {}.", entry.function),
+            };
+            if let Some(dim) = function.dim() {
+                if dim != entry.dim {
+                    panic!("The function {} requires dimensionality {}, but {} was requested.", entry.function, dim, entry.dim);
+                }
+            }
+            let shift = entry.shift.clone().unwrap_or_else(|| vec![0.0; entry.dim]);
+            if shift.len() != entry.dim {
+                panic!("A shift with size {} was used with a suite entry of dimensionality {}.", shift.len(), entry.dim);
+            }
+            Box::new(SuiteMember { function, dim: entry.dim, shift }) as Box<dyn DynSingleObjective>
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::{get_single, all_single_objective, function_catalog, init_regions, single_by_difficulty, build_suite, SuiteSpec, SuiteEntry, DynSingleObjective, DynMultiObjective, SINGLE_NAMES};
+    use crate::multi::BinhKorn;
+    use crate::single::{Sphere, Rastrigin, Rosenbrock, RosenbrockConst1, Michalewicz, G6};
+    use crate::{InitRegion, DynBounded};
+
+    #[test]
+    fn check_sphere() {
+        let f = get_single("sphere").unwrap();
+        assert_eq!(f.f(&[0.0; 3]), f.minimum(3));
+        assert_eq!(f.minimizer(3), vec![0.0; 3]);
+    }
+
+    #[test]
+    fn check_domain() {
+        let f = get_single("rastrigin").unwrap();
+        assert_eq!(f.bounds(3), (-5.12, 5.12));
+        assert_eq!(f.dim(), None);
+        let f = get_single("sphere").unwrap();
+        assert_eq!(f.bounds(3), (f64::NEG_INFINITY, f64::INFINITY));
+        assert_eq!(get_single("rosenbrock_const1").unwrap().dim(), Some(2));
+    }
+
+    #[test]
+    fn check_perm_bounds() {
+        let f = get_single("perm").unwrap();
+        assert_eq!(f.bounds(2), (-2.0, 2.0));
+        assert_eq!(f.bounds(4), (-4.0, 4.0));
+        let info = function_catalog().into_iter().find(|info| info.name == "perm").unwrap();
+        assert_eq!(info.bounds, (-2.0, 2.0));
+        let perm = SINGLE_NAMES.iter().position(|name| *name == "perm").unwrap();
+        assert_eq!(init_regions(4)[perm], vec![(-4.0, 4.0); 4]);
+    }
+
+    #[test]
+    fn check_box_bounds() {
+        let f = get_single("g1").unwrap();
+        let bounds = f.box_bounds(13);
+        assert_eq!(bounds.len(), 13);
+        assert_eq!((bounds[0], bounds[9], bounds[12]), ((0.0, 1.0), (0.0, 100.0), (0.0, 1.0)));
+        assert_eq!(f.bounds(13), (0.0, 100.0));
+        let f = get_single("g6").unwrap();
+        assert_eq!(f.box_bounds(2), vec![(13.0, 100.0), (0.0, 100.0)]);
+        assert_eq!(f.bounds(2), (0.0, 100.0));
+        let g6 = SINGLE_NAMES.iter().position(|name| *name == "g6").unwrap();
+        assert_eq!(init_regions(2)[g6], vec![(13.0, 100.0), (0.0, 100.0)]);
+        assert_eq!(get_single("rastrigin").unwrap().box_bounds(3), vec![(-5.12, 5.12); 3]);
+        assert!(G6::in_dyn_bounds(vec![14.0, 1.0]));
+        assert!(!G6::in_dyn_bounds(vec![1.0, 1.0]));
+    }
+
+    #[test]
+    fn check_trid_bounds() {
+        let f = get_single("trid").unwrap();
+        assert_eq!(f.bounds(3), (-9.0, 9.0));
+        assert_eq!(f.minimum(3), -7.0);
+        let info = function_catalog().into_iter().find(|info| info.name == "trid").unwrap();
+        assert_eq!((info.bounds, info.minimum), ((-4.0, 4.0), -2.0));
+    }
+
+    #[test]
+    fn check_minimizers() {
+        for name in SINGLE_NAMES {
+            let f = get_single(name).unwrap();
+            let n = f.dim().unwrap_or(2);
+            assert!((f.f(&f.minimizer(n)) - f.minimum(n)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn check_all() {
+        let functions = all_single_objective();
+        assert_eq!(functions.len(), SINGLE_NAMES.len());
+        for f in functions.iter() {
+            let n = f.dim().unwrap_or(2);
+            assert!((f.f(&f.minimizer(n)) - f.minimum(n)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn check_catalog() {
+        let catalog = function_catalog();
+        assert_eq!(catalog.len(), SINGLE_NAMES.len());
+        for (info, name) in catalog.iter().zip(SINGLE_NAMES.iter()) {
+            assert_eq!(info.name, *name);
+            let f = get_single(name).unwrap();
+            assert_eq!(info.reference_dim, f.dim().unwrap_or(2));
+            assert!((f.f(&f.minimizer(info.reference_dim)) - info.minimum).abs() < 1e-4);
+            assert_eq!(info.bounds, f.bounds(info.reference_dim));
+            assert_eq!(info.dim, f.dim());
+        }
+        let rastrigin = catalog.iter().find(|info| info.name == "rastrigin").unwrap();
+        assert!(rastrigin.separable && rastrigin.multimodal && rastrigin.dim.is_none());
+        let michalewicz = catalog.iter().find(|info| info.name == "michalewicz").unwrap();
+        assert!((michalewicz.minimum + 1.8013).abs() < 1e-4);
+    }
+
+    #[test]
+    fn check_small_inputs() {
+        for name in SINGLE_NAMES.iter().filter(|name| get_single(name).unwrap().dim().is_none()) {
+            assert!(std::panic::catch_unwind(|| get_single(name).unwrap().f(&[])).is_err());
+            let one_d = std::panic::catch_unwind(|| get_single(name).unwrap().f(&[0.5]));
+            if ["rosenbrock", "pathological", "brown"].contains(name) {
+                assert!(one_d.is_err());
+            } else {
+                assert!(one_d.unwrap().is_finite());
+            }
+        }
+    }
+
+    #[test]
+    fn check_init_regions() {
+        for (region, name) in init_regions(3).iter().zip(SINGLE_NAMES.iter()) {
+            let f = get_single(name).unwrap();
+            let bounds = f.box_bounds(f.dim().unwrap_or(3));
+            assert_eq!(region.len(), bounds.len());
+            assert!(region.iter().all(|&(a, b)| a.is_finite() && b.is_finite()));
+            assert!(region.iter().zip(bounds.iter()).all(|(&(a, b), &(lower, upper))| lower <= a && a < b && b <= upper));
+        }
+        assert_eq!(init_regions(3)[0], vec![(-100.0, 0.0); 3]);
+        assert_eq!(init_regions(3)[1], vec![(-5.12, 0.0); 3]);
+        assert_eq!(<Michalewicz as InitRegion>::init_region(11).len(), 11);
+    }
+
+    #[test]
+    fn check_difficulty() {
+        let ordered = single_by_difficulty();
+        assert_eq!(ordered.len(), SINGLE_NAMES.len());
+        let position = |name| ordered.iter().position(|n| *n == name).unwrap();
+        assert_eq!(ordered[0], "sphere");
+        assert!(position("sphere") < position("rastrigin"));
+        assert!(position("rastrigin") < position("ackley"));
+        assert!(position("discus") > position("sphere"));
+    }
+
+    #[test]
+    fn check_heterogeneous() {
+        let functions: Vec<Box<dyn DynSingleObjective>> = vec![
+            Box::new(Sphere {}),
+            Box::new(Rastrigin {}),
+            Box::new(Rosenbrock {}),
+            Box::new(RosenbrockConst1 {}),
+        ];
+        for f in functions.iter() {
+            let n = f.dim().unwrap_or(5);
+            assert!((f.f(&f.minimizer(n)) - f.minimum(n)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn check_static_multi() {
+        let f: Box<dyn DynMultiObjective> = Box::new(BinhKorn {});
+        assert_eq!(f.num_objectives(), 2);
+        assert_eq!(f.dim(), 2);
+        assert_eq!(f.evaluate(&[1.0, 1.0]), vec![8.0, 32.0]);
+    }
+
+    #[test]
+    fn check_unknown() {
+        assert!(get_single("not_a_function").is_none());
+    }
+
+    fn spec() -> SuiteSpec {
+        SuiteSpec {
+            entries: vec![
+                SuiteEntry { function: String::from("sphere"), dim: 3, shift: None, seed: Some(1) },
+                SuiteEntry { function: String::from("rastrigin"), dim: 2, shift: Some(vec![1.0, -0.5]), seed: None },
+                SuiteEntry { function: String::from("rosenbrock_const1"), dim: 2, shift: None, seed: Some(2) },
+            ],
+        }
+    }
+
+    #[test]
+    fn check_suite() {
+        let spec = spec();
+        let suite = build_suite(&spec);
+        assert_eq!(suite.len(), spec.entries.len());
+        for (f, entry) in suite.iter().zip(spec.entries.iter()) {
+            let reference = get_single(&entry.function).unwrap();
+            assert_eq!(f.dim(), Some(entry.dim));
+            assert_eq!(f.bounds(entry.dim), reference.bounds(entry.dim));
+            assert_eq!(f.f(&f.minimizer(entry.dim)), reference.minimum(entry.dim));
+        }
+        assert_eq!(suite[1].minimizer(2), vec![1.0, -0.5]);
+        assert_eq!(suite[1].f(&[1.5, 0.5]), get_single("rastrigin").unwrap().f(&[0.5, 1.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_suite_unknown() {
+        build_suite(&SuiteSpec {
+            entries: vec![SuiteEntry { function: String::from("not_a_function"), dim: 2, shift: None, seed: None }],
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn check_suite_round_trip() {
+        let spec = spec();
+        let json = serde_json::to_string(&spec).unwrap();
+        let parsed: SuiteSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, spec);
+        let suite = build_suite(&parsed);
+        for (f, entry) in suite.iter().zip(spec.entries.iter()) {
+            assert_eq!(f.dim(), Some(entry.dim));
+            assert_eq!(f.f(&f.minimizer(entry.dim)), get_single(&entry.function).unwrap().minimum(entry.dim));
+        }
+    }
+}
@@ -1,6 +1,84 @@
 //! This module contains single-objective functions
 
-use crate::{NDimensional, UnConstrained, UnBounded, Bounded, SingleObjective, FixedDimensional, Constrained};
+use crate::{NDimensional, UnConstrained, UnBounded, Bounded, DynBounded, Domain, SingleObjective, FixedDimensional, Constrained, BoxBounded, Properties};
+use crate::registry::DynSingleObjective;
+use core::f64::consts::FRAC_PI_2;
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+
+/// This function returns true when a user-supplied `analytic` gradient of `F` at `x` agrees with the
+/// central-difference gradient, with a maximum componentwise error below `tol`
+pub fn check_gradient<F: SingleObjective>(x: Vec<f64>, analytic: &[f64], tol: f64) -> bool {
+    let numeric = F::gradient(x);
+    (numeric.len() == analytic.len())
+        && numeric.iter().zip(analytic.iter()).all(|(ni, ai)| (ni - ai).abs() < tol)
+}
+
+#[cfg(test)]
+mod check_gradient_tests {
+    use super::{check_gradient, Sphere, Rosenbrock};
+
+    #[test]
+    fn check_sphere() {
+        let x = vec![1.0, -2.0, 0.5];
+        let analytic: Vec<f64> = x.iter().map(|xi| 2.0*xi).collect();
+        assert!(check_gradient::<Sphere>(x.clone(), &analytic, 1e-6));
+        let wrong = x.clone();
+        assert!(!check_gradient::<Sphere>(x, &wrong, 1e-6));
+    }
+
+    #[test]
+    fn check_rosenbrock() {
+        let x: Vec<f64> = vec![-1.2, 1.0];
+        let analytic = vec![
+            -400.0*x[0]*(x[1] - x[0].powi(2)) - 2.0*(1.0 - x[0]),
+            200.0*(x[1] - x[0].powi(2)),
+        ];
+        assert!(check_gradient::<Rosenbrock>(x.clone(), &analytic, 1e-4));
+        assert!(!check_gradient::<Rosenbrock>(x, &analytic[..1], 1e-4));
+    }
+}
+
+/// This function is an empirical convexity test for `F`, which returns false when the midpoint
+/// convexity inequality `f((a + b)/2) <= (f(a) + f(b))/2` is violated for any pair of `points`
+pub fn is_convex_sample<F: SingleObjective>(points: &[Vec<f64>]) -> bool {
+    let values: Vec<f64> = points.iter().map(|x| F::f(x.clone())).collect();
+    for (i, a) in points.iter().enumerate() {
+        for (j, b) in points.iter().enumerate().skip(i + 1) {
+            let midpoint: Vec<f64> = a.iter().zip(b.iter()).map(|(ai, bi)| 0.5*(ai + bi)).collect();
+            let chord = 0.5*(values[i] + values[j]);
+            if F::f(midpoint) > chord + 1e-12*chord.abs().max(1.0) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod is_convex_sample_tests {
+    use super::{is_convex_sample, Sphere, Rastrigin};
+
+    fn points() -> Vec<Vec<f64>> {
+        let mut points = Vec::new();
+        for i in 0..7 {
+            for j in 0..7 {
+                points.push(vec![-3.0 + (i as f64), -2.7 + 0.9*(j as f64)]);
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn check_sphere() {
+        assert!(is_convex_sample::<Sphere>(&points()));
+    }
+
+    #[test]
+    fn check_rastrigin() {
+        assert!(!is_convex_sample::<Rastrigin>(&points()));
+    }
+}
 
 /// This is the Sphere function.
 ///
@@ -15,15 +93,36 @@ impl NDimensional for Sphere {}
 impl UnConstrained for Sphere {}
 impl UnBounded for Sphere {}
 
+impl DynBounded for Sphere {
+    fn bounds(_n: usize) -> (f64, f64) {
+        <Sphere as UnBounded>::BOUNDS
+    }
+}
+
+impl Properties for Sphere {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+
+    fn condition_number(_n: usize) -> Option<f64> {
+        Some(1.0)
+    }
+}
+
 impl SingleObjective for Sphere {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
         let mut f = 0f64;
         for xi in x {
-            f -= xi.powi(2);
+            f += xi.powi(2);
         }
         f
     }
@@ -66,21 +165,39 @@ impl Bounded for Rastrigin {
     const BOUNDS: (f64, f64) = (-5.12, 5.12);
 }
 
-impl SingleObjective for Rastrigin {
-    /// The global minimum is constant and zero
-    const MINIMUM: f64 = 0.0;
+impl Properties for Rastrigin {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
 
-    /// Function for evaluating
-    fn f(x: Vec<f64>) -> f64 {
-        let a = 10.0;
+    fn num_local_minima(n: usize) -> Option<usize> {
+        11usize.checked_pow(n as u32)
+    }
+}
+
+impl Rastrigin {
+    /// Function for evaluating with a specific amplitude `a` of the cosine modulation, which
+    /// leaves the global minimum at the origin for any value
+    pub fn f_with_a(x: Vec<f64>, a: f64) -> f64 {
+        Self::check_input(&x);
         let n = x.len() ;
         let mut fx = a*(n as f64);
 
         for xi in x {
-            fx += xi.powi(2) - a*(2.0*xi*std::f64::consts::PI).cos();
+            fx += xi.powi(2) - a*(2.0*xi*core::f64::consts::PI).cos();
         }
         fx
     }
+}
+
+impl SingleObjective for Rastrigin {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_with_a(x, 10.0)
+    }
 
     /// This function returns the minimizer (argument that will return the global minimum
     fn minimizer(n: usize) -> Vec<f64> {
@@ -101,6 +218,15 @@ mod rastrigin_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_a() {
+        let x = vec![0.5, -1.25, 3.0];
+        assert_eq!(F::f_with_a(x.clone(), 10.0), F::f(x.clone()));
+        assert_eq!(F::f_with_a(vec![0.0; 3], 5.0), 0.0);
+        assert_eq!(F::f_with_a(F::minimizer(F::HIGH_D), 20.0), F::MINIMUM);
+        assert!(F::f_with_a(x.clone(), 20.0) > F::f(x));
+    }
 }
 
 /// This is the Rosenbrock function.
@@ -112,7 +238,9 @@ mod rastrigin_tests {
 /// ![](https://upload.wikimedia.org/wikipedia/commons/thumb/7/7e/Rosenbrock%27s_function_in_3D.pdf/page1-800px-Rosenbrock%27s_function_in_3D.pdf.jpg)
 pub struct Rosenbrock {}
 
-impl NDimensional for Rosenbrock {}
+impl NDimensional for Rosenbrock {
+    const MIN_D: usize = 2;
+}
 impl UnConstrained for Rosenbrock {}
 
 impl Bounded for Rosenbrock {
@@ -120,12 +248,19 @@ impl Bounded for Rosenbrock {
     const BOUNDS: (f64, f64) = (-5.0, 10.0);
 }
 
+impl Properties for Rosenbrock {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
 impl SingleObjective for Rosenbrock {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
         let n = x.len();
         let mut fx = 0.0;
         for i in 0..(n-1) {
@@ -153,6 +288,25 @@ mod rosenbrock_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_reference() {
+        assert_eq!(F::f(vec![0.0; 2]), 1.0);
+        assert_eq!(F::f(vec![2.0, 2.0]), 401.0);
+        assert_eq!(F::f(vec![-1.0, 1.0]), 4.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn check_one_d() {
+        F::f(vec![0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn check_empty() {
+        F::f(vec![]);
+    }
 }
 
 /// This is the Ackley function.
@@ -172,23 +326,38 @@ impl Bounded for Ackley {
     const BOUNDS: (f64, f64) = (-5.0, 5.0);
 }
 
-impl SingleObjective for Ackley {
-    /// The global minimum is constant and zero
-    const MINIMUM: f64 = 0.0;
+impl Properties for Ackley {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
 
-    /// Function for evaluating
-    fn f(x: Vec<f64>) -> f64 {
+impl Ackley {
+    /// Function for evaluating with specific values of the depth `a`, the decay rate `b`, and the
+    /// frequency `c`
+    pub fn f_with_params(x: Vec<f64>, a: f64, b: f64, c: f64) -> f64 {
+        Self::check_input(&x);
         let n=x.len();
         let mut fx = 0.0;
         let mut square_sum = 0.0;
         let mut cosine_sum = 0.0;
         for xi in x {
             square_sum += xi.powi(2);
-            cosine_sum += (2.0*std::f64::consts::PI*xi).cos();
+            cosine_sum += (c*xi).cos();
         }
-        fx += -20.0*(-0.2*(0.5*square_sum).sqrt()).exp();
+        fx += -a*(-b*(square_sum/(n as f64)).sqrt()).exp();
         fx -= (cosine_sum/(n as f64)).exp();
-        fx + std::f64::consts::E + 20.0
+        fx + core::f64::consts::E + a
+    }
+}
+
+impl SingleObjective for Ackley {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_with_params(x, 20.0, 0.2, 2.0*core::f64::consts::PI)
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
@@ -210,6 +379,22 @@ mod ackley_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_reference() {
+        assert!((F::f(vec![0.5, -1.0, 2.0]) - 5.972029779887098).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_params() {
+        let x = vec![0.5, -1.0, 2.0];
+        let c = 2.0*core::f64::consts::PI;
+        assert_eq!(F::f_with_params(x.clone(), 20.0, 0.2, c), F::f(x.clone()));
+        let radius = (x.iter().map(|xi| xi.powi(2)).sum::<f64>()/3.0).sqrt();
+        let shift = F::f_with_params(x.clone(), 30.0, 0.2, c) - F::f(x);
+        assert!((shift - 10.0*(1.0 - (-0.2*radius).exp())).abs() < 1e-12);
+        assert!(F::f_with_params(vec![0.0; 3], 30.0, 0.5, 1.0).abs() < 1e-12);
+    }
 }
 
 /// This is the Matyas function.
@@ -229,12 +414,19 @@ impl Bounded for Matyas {
     const BOUNDS: (f64, f64) = (-10.0, 10.0);
 }
 
+impl Properties for Matyas {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
 impl SingleObjective for Matyas {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
         let mut square_sum = 0.0;
         let mut prod = 1.0;
         for xi in x {
@@ -282,19 +474,33 @@ impl Bounded for Griewank {
     const BOUNDS: (f64, f64) = (-600.0, 600.0);
 }
 
-impl SingleObjective for Griewank {
-    /// The global minimum is constant and zero
-    const MINIMUM: f64 = 0.0;
+impl Properties for Griewank {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
 
-    /// Function for evaluating
-    fn f(x: Vec<f64>) -> f64 {
+impl Griewank {
+    /// Function for evaluating with a specific `divisor` of the quadratic term
+    pub fn f_with_params(x: Vec<f64>, divisor: f64) -> f64 {
+        Self::check_input(&x);
         let mut cosine_prod = 1.0;
         let mut square_sum = 0.0;
         for (i, xi) in x.iter().enumerate() {
             square_sum += xi.powi(2);
             cosine_prod *= (xi/((i+1) as f64).sqrt()).cos();
         }
-        1.0 + square_sum/4000.0 - cosine_prod
+        1.0 + square_sum/divisor - cosine_prod
+    }
+}
+
+impl SingleObjective for Griewank {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_with_params(x, 4000.0)
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
@@ -316,6 +522,14 @@ mod griewank_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_params() {
+        let x = vec![10.0, -20.0, 5.0];
+        assert_eq!(F::f_with_params(x.clone(), 4000.0), F::f(x.clone()));
+        assert_eq!(F::f_with_params(F::minimizer(F::LOW_D), 200.0), F::MINIMUM);
+        assert!(F::f_with_params(x.clone(), 200.0) > F::f(x));
+    }
 }
 
 /// This is the Ridge function.
@@ -335,19 +549,53 @@ impl Bounded for Ridge {
     const BOUNDS: (f64, f64) = (-5.0, 5.0);
 }
 
-impl SingleObjective for Ridge {
-    /// The global minimum is constant and zero
-    const MINIMUM: f64 = -5.0;
+impl Properties for Ridge {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+}
 
-    /// Function for evaluating
-    fn f(x: Vec<f64>) -> f64 {
-        let d = 1.0;
-        let alpha = 0.0;
+/// This is the configuration of the tunable parameters of the Ridge function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RidgeConfig {
+    /// The scale of the ridge
+    pub d: f64,
+
+    /// The exponent of the ridge
+    pub alpha: f64,
+}
+
+impl Default for RidgeConfig {
+    /// The canonical parameters are `d = 1` and `alpha = 0.5`
+    fn default() -> Self {
+        RidgeConfig { d: 1.0, alpha: 0.5 }
+    }
+}
+
+impl Ridge {
+    /// Function for evaluating with the parameters in `config`
+    pub fn f_config(x: Vec<f64>, config: &RidgeConfig) -> f64 {
+        Self::f_with_params(x, config.d, config.alpha)
+    }
+
+    /// Function for evaluating with specific values of the ridge scale `d` and exponent `alpha`
+    pub fn f_with_params(x: Vec<f64>, d: f64, alpha: f64) -> f64 {
+        Self::check_input(&x);
         let mut square_sum = 0.0;
         for xi in x.iter().skip(1) {
             square_sum += xi.powi(2);
         }
-        -1.0 + x[0] + d * square_sum.powf(alpha)
+        x[0] + d * square_sum.powf(alpha)
+    }
+}
+
+impl SingleObjective for Ridge {
+    /// The global minimum is -5, at the lower bound of the first variable
+    const MINIMUM: f64 = -5.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_config(x, &RidgeConfig::default())
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
@@ -360,7 +608,7 @@ impl SingleObjective for Ridge {
 
 #[cfg(test)]
 mod ridge_tests {
-    use super::{Ridge as F, NDimensional, SingleObjective};
+    use super::{Ridge as F, RidgeConfig, NDimensional, SingleObjective};
 
     #[test]
     fn low_d() {
@@ -371,6 +619,22 @@ mod ridge_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_ridge() {
+        assert_eq!(F::f(vec![-5.0, 3.0, 4.0]), 0.0);
+        assert_eq!(F::f(vec![1.0, 0.0]), 1.0);
+        assert!(F::f(vec![-5.0, 0.1]) > F::MINIMUM);
+        assert_eq!(F::f_with_params(vec![-5.0, 2.0], 2.0, 1.0), 3.0);
+    }
+
+    #[test]
+    fn check_config() {
+        let x = vec![-1.0, 2.0, 0.5];
+        assert_eq!(F::f_config(x.clone(), &RidgeConfig::default()), F::f(x.clone()));
+        let config = RidgeConfig { d: 2.0, alpha: 1.0 };
+        assert_eq!(F::f_config(x.clone(), &config), F::f_with_params(x, 2.0, 1.0));
+    }
 }
 
 /// This is the Zakharov function.
@@ -390,12 +654,23 @@ impl Bounded for Zakharov {
     const BOUNDS: (f64, f64) = (-5.0, 10.0);
 }
 
+impl Properties for Zakharov {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+}
+
 impl SingleObjective for Zakharov {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
         let mut square_sum: f64 = 0.0;
         let mut sum_ixi: f64 = 0.0;
         for (i, xi) in x.iter().enumerate() {
@@ -433,6 +708,9 @@ mod zakharov_tests {
 /// like in 2D:
 ///
 /// ![](http://benchmarkfcns.xyz/benchmarkfcns/plots/salomonfcn.png)
+///
+/// The function only depends on the distance `r` from the origin, so the global minimizer is
+/// surrounded by continuous rings of local minima, lying just inside each integer radius.
 pub struct Salomon {}
 
 impl NDimensional for Salomon {}
@@ -443,17 +721,24 @@ impl Bounded for Salomon {
     const BOUNDS: (f64, f64) = (-100.0, 100.0);
 }
 
+impl Properties for Salomon {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
 impl SingleObjective for Salomon {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
 
     /// Function for evaluating
     fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
         let mut square_sum = 0.0;
         for xi in x {
             square_sum += xi.powi(2);
         }
-        1.0 - (2.0*std::f64::consts::PI*square_sum.sqrt()).cos() + 0.1*square_sum.sqrt()
+        1.0 - (2.0*core::f64::consts::PI*square_sum.sqrt()).cos() + 0.1*square_sum.sqrt()
     }
 
     /// This function returns the minimizer (argument that will return the global minimum
@@ -475,6 +760,21 @@ mod salomon_tests {
     fn high_d() {
         F::check_minimizer(F::HIGH_D)
     }
+
+    #[test]
+    fn check_first_ring() {
+        // The first ring is where the derivative 2 pi sin(2 pi r) + 0.1 vanishes, just inside r = 1
+        let epsilon = 0.1/(2.0*core::f64::consts::PI);
+        let radius = 1.0 - epsilon.asin()/(2.0*core::f64::consts::PI);
+        let ring_minimum = 1.0 - (1.0 - epsilon.powi(2)).sqrt() + 0.1*radius;
+        for angle in [0.0, 0.7, 2.0, 4.5] {
+            let x = vec![radius*f64::cos(angle), radius*f64::sin(angle)];
+            assert!((F::f(x) - ring_minimum).abs() < 1e-12);
+        }
+        assert!(F::f(vec![radius - 1e-3, 0.0]) > ring_minimum);
+        assert!(F::f(vec![radius + 1e-3, 0.0]) > ring_minimum);
+        assert!(ring_minimum > F::MINIMUM);
+    }
 }
 
 /// This is a constrained version of the Rosenbrock function.
@@ -488,6 +788,12 @@ pub struct RosenbrockConst1 {}
 
 impl UnBounded for RosenbrockConst1 {}
 
+impl DynBounded for RosenbrockConst1 {
+    fn bounds(_n: usize) -> (f64, f64) {
+        <RosenbrockConst1 as UnBounded>::BOUNDS
+    }
+}
+
 impl Constrained for RosenbrockConst1 {
     const NH: usize = 0;
     const NG: usize = 2;
@@ -508,6 +814,12 @@ impl FixedDimensional for RosenbrockConst1 {
     const D: usize = 2;
 }
 
+impl Properties for RosenbrockConst1 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
 impl SingleObjective for RosenbrockConst1 {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
@@ -547,6 +859,12 @@ pub struct RosenbrockConst2 {}
 
 impl UnBounded for RosenbrockConst2 {}
 
+impl DynBounded for RosenbrockConst2 {
+    fn bounds(_n: usize) -> (f64, f64) {
+        <RosenbrockConst2 as UnBounded>::BOUNDS
+    }
+}
+
 impl Constrained for RosenbrockConst2 {
     const NH: usize = 0;
     const NG: usize = 1;
@@ -566,6 +884,12 @@ impl FixedDimensional for RosenbrockConst2 {
     const D: usize = 2;
 }
 
+impl Properties for RosenbrockConst2 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
 impl SingleObjective for RosenbrockConst2 {
     /// The global minimum is constant and zero
     const MINIMUM: f64 = 0.0;
@@ -590,4 +914,3088 @@ mod rosenbrock_const2_tests {
     fn low_d() {
         F::check_minimizer(F::D)
     }
-}
\ No newline at end of file
+}
+
+/// This is the Happy Cat function.
+///
+/// The function is borrowed from Beyer and Finck, "HappyCat - A Simple Function Class Where
+/// Well-Known Direct Search Algorithms Do Fail" (2012), and is part of the CEC 2014 suite. It is
+/// N-dimensional, and its minimum lies at the bottom of a narrow, curved valley around the sphere
+/// `|x|^2 = n`, which makes it hard to locate precisely.
+pub struct HappyCat {}
+
+impl NDimensional for HappyCat {}
+impl UnConstrained for HappyCat {}
+
+impl Bounded for HappyCat {
+    const BOUNDS: (f64, f64) = (-2.0, 2.0);
+}
+
+impl Properties for HappyCat {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for HappyCat {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let alpha = 1.0/8.0;
+        let n = x.len() as f64;
+        let mut square_sum = 0.0;
+        let mut sum = 0.0;
+        for xi in x {
+            square_sum += xi.powi(2);
+            sum += xi;
+        }
+        ((square_sum - n).powi(2)).powf(alpha) + (0.5*square_sum + sum)/n + 0.5
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![-1.0; n]
+    }
+}
+
+#[cfg(test)]
+mod happy_cat_tests {
+    use super::{HappyCat as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-6)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-6)
+    }
+}
+
+/// This is the HGBat function.
+///
+/// The function is borrowed from the CEC 2014 benchmark suite, where it is defined alongside the
+/// Happy Cat function. It is N-dimensional, and shares the form of its second and third terms with
+/// Happy Cat.
+pub struct HgBat {}
+
+impl NDimensional for HgBat {}
+impl UnConstrained for HgBat {}
+
+impl Bounded for HgBat {
+    const BOUNDS: (f64, f64) = (-15.0, 15.0);
+}
+
+impl Properties for HgBat {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for HgBat {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let n = x.len() as f64;
+        let mut square_sum = 0.0;
+        let mut sum = 0.0;
+        for xi in x {
+            square_sum += xi.powi(2);
+            sum += xi;
+        }
+        (square_sum.powi(2) - sum.powi(2)).abs().sqrt() + (0.5*square_sum + sum)/n + 0.5
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![-1.0; n]
+    }
+}
+
+#[cfg(test)]
+mod hgbat_tests {
+    use super::{HgBat as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+}
+
+/// This is the Bohachevsky N.1 function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html). This function is
+/// specifically 2 dimensional, and is a bowl-shaped quadratic with cosine ripples.
+pub struct BohachevskyN1 {}
+
+impl UnConstrained for BohachevskyN1 {}
+
+impl FixedDimensional for BohachevskyN1 {
+    const D: usize = 2;
+}
+
+impl Bounded for BohachevskyN1 {
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for BohachevskyN1 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for BohachevskyN1 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = core::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0]).cos() - 0.4*(4.0*pi*x[1]).cos() + 0.7
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0; 2]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n1_tests {
+    use super::{BohachevskyN1 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+
+    #[test]
+    fn check_value() {
+        let expected = 1.0/36.0 + 1.0/32.0 + 0.7;
+        assert!((F::f(vec![1.0/6.0, 0.125]) - expected).abs() < 1e-12);
+    }
+}
+
+/// This is the Bohachevsky N.2 function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html). This function is
+/// specifically 2 dimensional, and is a bowl-shaped quadratic with cosine ripples.
+pub struct BohachevskyN2 {}
+
+impl UnConstrained for BohachevskyN2 {}
+
+impl FixedDimensional for BohachevskyN2 {
+    const D: usize = 2;
+}
+
+impl Bounded for BohachevskyN2 {
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for BohachevskyN2 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for BohachevskyN2 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = core::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0]).cos()*(4.0*pi*x[1]).cos() + 0.3
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0; 2]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n2_tests {
+    use super::{BohachevskyN2 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+
+    #[test]
+    fn check_value() {
+        let expected = 1.0/36.0 + 1.0/32.0 + 0.3;
+        assert!((F::f(vec![1.0/6.0, 0.125]) - expected).abs() < 1e-12);
+    }
+}
+
+/// This is the Bohachevsky N.3 function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/boha.html). This function is
+/// specifically 2 dimensional, and is a bowl-shaped quadratic with cosine ripples.
+pub struct BohachevskyN3 {}
+
+impl UnConstrained for BohachevskyN3 {}
+
+impl FixedDimensional for BohachevskyN3 {
+    const D: usize = 2;
+}
+
+impl Bounded for BohachevskyN3 {
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for BohachevskyN3 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for BohachevskyN3 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let pi = core::f64::consts::PI;
+        x[0].powi(2) + 2.0*x[1].powi(2) - 0.3*(3.0*pi*x[0] + 4.0*pi*x[1]).cos() + 0.3
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.0; 2]
+    }
+}
+
+#[cfg(test)]
+mod bohachevsky_n3_tests {
+    use super::{BohachevskyN3 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+
+    #[test]
+    fn check_value() {
+        let expected = 1.0/36.0 + 1.0/32.0 + 0.6;
+        assert!((F::f(vec![1.0/6.0, 0.125]) - expected).abs() < 1e-12);
+    }
+}
+
+/// These are the weights of the terms of the Hartmann functions
+const HARTMANN_ALPHA: [f64; 4] = [1.0, 1.2, 3.0, 3.2];
+
+/// This is the `A` coefficient matrix of the Hartmann 3-D function
+const HARTMANN3_A: [[f64; 3]; 4] = [
+    [3.0, 10.0, 30.0],
+    [0.1, 10.0, 35.0],
+    [3.0, 10.0, 30.0],
+    [0.1, 10.0, 35.0],
+];
+
+/// This is the `P` coefficient matrix of the Hartmann 3-D function
+const HARTMANN3_P: [[f64; 3]; 4] = [
+    [0.3689, 0.1170, 0.2673],
+    [0.4699, 0.4387, 0.7470],
+    [0.1091, 0.8732, 0.5547],
+    [0.0381, 0.5743, 0.8828],
+];
+
+/// This is the Hartmann 3-D function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/hart3.html). This function is
+/// specifically 3 dimensional, and has four local minima.
+pub struct Hartmann3 {}
+
+impl UnConstrained for Hartmann3 {}
+
+impl FixedDimensional for Hartmann3 {
+    const D: usize = 3;
+}
+
+impl Bounded for Hartmann3 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl Properties for Hartmann3 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(4)
+    }
+}
+
+impl SingleObjective for Hartmann3 {
+    /// The global minimum is approximately -3.86278
+    const MINIMUM: f64 = -3.86278;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for i in 0..4 {
+            let mut inner = 0.0;
+            for j in 0..Self::D {
+                inner += HARTMANN3_A[i][j]*(x[j] - HARTMANN3_P[i][j]).powi(2);
+            }
+            fx -= HARTMANN_ALPHA[i]*(-inner).exp();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.114614, 0.555649, 0.852547]
+    }
+}
+
+#[cfg(test)]
+mod hartmann3_tests {
+    use super::{Hartmann3 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-4)
+    }
+}
+
+/// This is the `A` coefficient matrix of the Hartmann 6-D function
+const HARTMANN6_A: [[f64; 6]; 4] = [
+    [10.0, 3.0, 17.0, 3.5, 1.7, 8.0],
+    [0.05, 10.0, 17.0, 0.1, 8.0, 14.0],
+    [3.0, 3.5, 1.7, 10.0, 17.0, 8.0],
+    [17.0, 8.0, 0.05, 10.0, 0.1, 14.0],
+];
+
+/// This is the `P` coefficient matrix of the Hartmann 6-D function
+const HARTMANN6_P: [[f64; 6]; 4] = [
+    [0.1312, 0.1696, 0.5569, 0.0124, 0.8283, 0.5886],
+    [0.2329, 0.4135, 0.8307, 0.3736, 0.1004, 0.9991],
+    [0.2348, 0.1451, 0.3522, 0.2883, 0.3047, 0.6650],
+    [0.4047, 0.8828, 0.8732, 0.5743, 0.1091, 0.0381],
+];
+
+/// This is the Hartmann 6-D function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/hart6.html). This function is
+/// specifically 6 dimensional, has six local minima, and is widely used to test Bayesian
+/// optimization methods.
+pub struct Hartmann6 {}
+
+impl UnConstrained for Hartmann6 {}
+
+impl FixedDimensional for Hartmann6 {
+    const D: usize = 6;
+}
+
+impl Bounded for Hartmann6 {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl Properties for Hartmann6 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(6)
+    }
+}
+
+impl SingleObjective for Hartmann6 {
+    /// The global minimum is approximately -3.32237
+    const MINIMUM: f64 = -3.32237;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for i in 0..4 {
+            let mut inner = 0.0;
+            for j in 0..Self::D {
+                inner += HARTMANN6_A[i][j]*(x[j] - HARTMANN6_P[i][j]).powi(2);
+            }
+            fx -= HARTMANN_ALPHA[i]*(-inner).exp();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![0.20169, 0.150011, 0.476874, 0.275332, 0.311652, 0.6573]
+    }
+}
+
+#[cfg(test)]
+mod hartmann6_tests {
+    use super::{Hartmann6 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-4)
+    }
+}
+
+/// This is the Colville function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/colville.html). This function
+/// is specifically 4 dimensional, and combines two Rosenbrock-like terms with coupling terms.
+pub struct Colville {}
+
+impl UnConstrained for Colville {}
+
+impl FixedDimensional for Colville {
+    const D: usize = 4;
+}
+
+impl Bounded for Colville {
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl Properties for Colville {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Colville {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        100.0*(x[0].powi(2) - x[1]).powi(2) + (x[0] - 1.0).powi(2)
+            + (x[2] - 1.0).powi(2) + 90.0*(x[2].powi(2) - x[3]).powi(2)
+            + 10.1*((x[1] - 1.0).powi(2) + (x[3] - 1.0).powi(2))
+            + 19.8*(x[1] - 1.0)*(x[3] - 1.0)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![1.0; 4]
+    }
+}
+
+#[cfg(test)]
+mod colville_tests {
+    use super::{Colville as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+
+    #[test]
+    fn check_value() {
+        assert!((F::f(vec![0.0; 4]) - 42.0).abs() < 1e-12);
+    }
+}
+
+/// This is the Perm function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/permdb.html). Although the
+/// function accepts a vector with an arbitrary number of inputs, its canonical bounds of `[-n, n]`
+/// depend on the dimensionality `n`. The parameter `beta` controls the difficulty of the function,
+/// with smaller values making the minimum harder to distinguish.
+pub struct Perm {}
+
+impl NDimensional for Perm {}
+impl UnConstrained for Perm {}
+
+impl Properties for Perm {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl DynBounded for Perm {
+    /// The bounds of the canonical Perm optimization problem are [-n, n].
+    fn bounds(n: usize) -> (f64, f64) {
+        (-(n as f64), n as f64)
+    }
+}
+
+/// This is the configuration of the tunable parameters of the Perm function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PermConfig {
+    /// The difficulty parameter, with smaller values making the minimum harder to distinguish
+    pub beta: f64,
+}
+
+impl Default for PermConfig {
+    /// The canonical parameter is `beta = 0.5`
+    fn default() -> Self {
+        PermConfig { beta: 0.5 }
+    }
+}
+
+impl Perm {
+    /// Function for evaluating with the parameters in `config`
+    pub fn f_config(x: Vec<f64>, config: &PermConfig) -> f64 {
+        Self::f_with_beta(x, config.beta)
+    }
+
+    /// Function for evaluating with a specific value of `beta`
+    pub fn f_with_beta(x: Vec<f64>, beta: f64) -> f64 {
+        Self::check_input(&x);
+        let n = x.len();
+        let mut fx = 0.0;
+        for i in 1..=n {
+            let mut inner = 0.0;
+            for (j, xj) in x.iter().enumerate() {
+                let j = (j + 1) as f64;
+                inner += (j.powi(i as i32) + beta)*((xj/j).powi(i as i32) - 1.0);
+            }
+            fx += inner.powi(2);
+        }
+        fx
+    }
+}
+
+impl SingleObjective for Perm {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_config(x, &PermConfig::default())
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        (1..=n).map(|i| i as f64).collect()
+    }
+}
+
+#[cfg(test)]
+mod perm_tests {
+    use super::{Perm as F, PermConfig, NDimensional, SingleObjective, DynBounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-6)
+    }
+
+    #[test]
+    fn four_d() {
+        F::check_minimizer_within(4, 1e-6)
+    }
+
+    #[test]
+    fn bounds_widen() {
+        assert_eq!(<F as DynBounded>::bounds(2), (-2.0, 2.0));
+        assert_eq!(<F as DynBounded>::bounds(10), (-10.0, 10.0));
+        assert!(F::in_dyn_bounds(F::minimizer(10)));
+        assert!(!F::in_dyn_bounds(vec![3.0, 0.0]));
+    }
+
+    #[test]
+    fn check_beta() {
+        assert_eq!(F::f_with_beta(F::minimizer(4), 10.0), 0.0);
+        assert_eq!(F::f_with_beta(vec![0.0, 1.0], 0.5), F::f(vec![0.0, 1.0]));
+        assert!(F::f_with_beta(vec![0.0, 1.0], 10.0) > F::f(vec![0.0, 1.0]));
+    }
+
+    #[test]
+    fn check_config() {
+        let x = vec![0.5, -1.0, 2.0];
+        assert_eq!(F::f_config(x.clone(), &PermConfig::default()), F::f(x.clone()));
+        assert_eq!(F::f_config(x.clone(), &PermConfig { beta: 10.0 }), F::f_with_beta(x, 10.0));
+    }
+}
+
+/// This is the Trid function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/trid.html). The function
+/// accepts a vector with an arbitrary number of inputs, and both its canonical bounds of
+/// `[-n^2, n^2]` and its global minimum of `-n(n + 4)(n - 1)/6` depend on the dimensionality `n`.
+pub struct Trid {}
+
+impl NDimensional for Trid {}
+impl UnConstrained for Trid {}
+
+impl Properties for Trid {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    /// The Hessian is tridiagonal with `2` on the diagonal and `-1` beside it, so its eigenvalues
+    /// are `2 - 2 cos(k pi/(n + 1))`
+    fn condition_number(n: usize) -> Option<f64> {
+        let c = (core::f64::consts::PI/((n + 1) as f64)).cos();
+        Some((1.0 + c)/(1.0 - c))
+    }
+}
+
+impl DynBounded for Trid {
+    /// The bounds of the canonical Trid optimization problem are [-n^2, n^2].
+    fn bounds(n: usize) -> (f64, f64) {
+        (-((n*n) as f64), (n*n) as f64)
+    }
+}
+
+impl SingleObjective for Trid {
+    /// The global minimum in two dimensions, see `minimum` for other dimensionalities
+    const MINIMUM: f64 = -2.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            fx += (xi - 1.0).powi(2);
+            if i > 0 {
+                fx -= xi*x[i - 1];
+            }
+        }
+        fx
+    }
+
+    /// The global minimum depends on the dimensionality
+    fn minimum(n: usize) -> f64 {
+        let n = n as f64;
+        -n*(n + 4.0)*(n - 1.0)/6.0
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        (1..=n).map(|i| (i*(n + 1 - i)) as f64).collect()
+    }
+}
+
+#[cfg(test)]
+mod trid_tests {
+    use super::{Trid as F, NDimensional, SingleObjective, DynBounded, Properties};
+
+    #[test]
+    fn low_d() {
+        assert_eq!(F::f(F::minimizer(F::LOW_D)), F::MINIMUM);
+        assert_eq!(F::minimum(F::LOW_D), F::MINIMUM);
+    }
+
+    #[test]
+    fn check_minima() {
+        for n in [1, 3, 6, 10, F::HIGH_D] {
+            assert!((F::f(F::minimizer(n)) - F::minimum(n)).abs() < 1e-9*F::minimum(n).abs().max(1.0));
+        }
+        assert_eq!(F::minimum(6), -50.0);
+        assert_eq!(F::minimum(10), -210.0);
+    }
+
+    #[test]
+    fn bounds_widen() {
+        assert_eq!(<F as DynBounded>::bounds(2), (-4.0, 4.0));
+        assert_eq!(<F as DynBounded>::bounds(10), (-100.0, 100.0));
+        assert!(F::in_dyn_bounds(F::minimizer(10)));
+        assert!(!F::in_dyn_bounds(F::minimizer(2).iter().map(|xi| xi + 3.0).collect()));
+        for n in 1..20 {
+            let (lower, upper) = <F as DynBounded>::bounds(n);
+            let (next_lower, next_upper) = <F as DynBounded>::bounds(n + 1);
+            assert!(next_lower < lower && next_upper > upper);
+            assert!(F::in_dyn_bounds(F::minimizer(n)));
+        }
+    }
+
+    #[test]
+    fn check_condition_number() {
+        assert!((F::condition_number(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((F::condition_number(2).unwrap() - 3.0).abs() < 1e-12);
+    }
+}
+
+/// This is the Powell Sum function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/sumpow.html). It should not be
+/// confused with the Powell function. The function accepts a vector with an arbitrary number of inputs.
+pub struct PowellSum {}
+
+impl NDimensional for PowellSum {}
+impl UnConstrained for PowellSum {}
+
+impl Bounded for PowellSum {
+    /// The bounds of the canonical Powell Sum optimization problem are [-1, 1].
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl Properties for PowellSum {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for PowellSum {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut f = 0f64;
+        for (i, xi) in x.iter().enumerate() {
+            f += xi.abs().powi(i as i32 + 2);
+        }
+        f
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod powell_sum_tests {
+    use super::{PowellSum as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+}
+
+/// This is the `a` matrix of foxhole centers of the De Jong N.5 function
+const DE_JONG_N5_A: [[f64; 25]; 2] = [
+    [
+        -32.0, -16.0, 0.0, 16.0, 32.0, -32.0, -16.0, 0.0, 16.0, 32.0, -32.0, -16.0, 0.0, 16.0, 32.0,
+        -32.0, -16.0, 0.0, 16.0, 32.0, -32.0, -16.0, 0.0, 16.0, 32.0,
+    ],
+    [
+        -32.0, -32.0, -32.0, -32.0, -32.0, -16.0, -16.0, -16.0, -16.0, -16.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        16.0, 16.0, 16.0, 16.0, 16.0, 32.0, 32.0, 32.0, 32.0, 32.0,
+    ],
+];
+
+/// This is the De Jong N.5 function, also known as Shekel's Foxholes.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/dejong5.html). This function is
+/// specifically 2 dimensional, and has 25 local minima arranged in a grid.
+pub struct DeJongN5 {}
+
+impl UnConstrained for DeJongN5 {}
+
+impl FixedDimensional for DeJongN5 {
+    const D: usize = 2;
+}
+
+impl Bounded for DeJongN5 {
+    const BOUNDS: (f64, f64) = (-65.536, 65.536);
+}
+
+impl Properties for DeJongN5 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(25)
+    }
+}
+
+impl SingleObjective for DeJongN5 {
+    /// The global minimum is approximately 0.998004
+    const MINIMUM: f64 = 0.998004;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut sum = 0.002;
+        for (i, (a1, a2)) in DE_JONG_N5_A[0].iter().zip(DE_JONG_N5_A[1].iter()).enumerate() {
+            sum += 1.0/((i + 1) as f64 + (x[0] - a1).powi(6) + (x[1] - a2).powi(6));
+        }
+        1.0/sum
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![-32.0, -32.0]
+    }
+}
+
+#[cfg(test)]
+mod de_jong_n5_tests {
+    use super::{DeJongN5 as F, FixedDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-2)
+    }
+}
+
+/// This is the Trigonometric 2 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and every term is coupled to the first variable.
+pub struct Trigonometric {}
+
+impl NDimensional for Trigonometric {}
+impl UnConstrained for Trigonometric {}
+
+impl Bounded for Trigonometric {
+    /// The bounds of the canonical Trigonometric optimization problem are [-500, 500].
+    const BOUNDS: (f64, f64) = (-500.0, 500.0);
+}
+
+impl Properties for Trigonometric {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Trigonometric {
+    /// The global minimum is constant and one
+    const MINIMUM: f64 = 1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let first = 6.0*(14.0*(x[0] - 0.9).powi(2)).sin().powi(2);
+        let mut fx = 1.0;
+        for xi in x {
+            let zi = (xi - 0.9).powi(2);
+            fx += 8.0*(7.0*zi).sin().powi(2) + first + zi;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.9; n]
+    }
+}
+
+#[cfg(test)]
+mod trigonometric_tests {
+    use super::{Trigonometric as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-6)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-6)
+    }
+}
+
+/// This is the Alpine N.1 function.
+///
+/// The function is borrowed from [here](http://benchmarkfcns.xyz/benchmarkfcns/alpinen1fcn.html).
+/// The function accepts a vector with an arbitrary number of inputs, and is not differentiable
+/// where any term changes sign.
+pub struct AlpineN1 {}
+
+impl NDimensional for AlpineN1 {}
+impl UnConstrained for AlpineN1 {}
+
+impl Bounded for AlpineN1 {
+    /// The bounds of the canonical Alpine N.1 optimization problem are [-10, 10].
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl Properties for AlpineN1 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for AlpineN1 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for xi in x {
+            fx += (xi*xi.sin() + 0.1*xi).abs();
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod alpine_n1_tests {
+    use super::{AlpineN1 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+}
+
+/// This is the location of the maximum of `sqrt(x) sin(x)` on [0, 10], used by the Alpine N.2 function
+const ALPINE_N2_ARGMAX: f64 = 7.917052684666206;
+
+/// This is the maximum of `sqrt(x) sin(x)` on [0, 10], used by the Alpine N.2 function
+const ALPINE_N2_MAX: f64 = 2.8081311800070052;
+
+/// This is the Alpine N.2 function.
+///
+/// The function is borrowed from [here](http://benchmarkfcns.xyz/benchmarkfcns/alpinen2fcn.html),
+/// and is negated so that it is a minimization problem. The function accepts a vector with an
+/// arbitrary number of inputs, and its minimum of `-2.808^n` depends on the dimensionality.
+pub struct AlpineN2 {}
+
+impl NDimensional for AlpineN2 {}
+impl UnConstrained for AlpineN2 {}
+
+impl Bounded for AlpineN2 {
+    /// The bounds of the canonical Alpine N.2 optimization problem are [0, 10].
+    const BOUNDS: (f64, f64) = (0.0, 10.0);
+}
+
+impl Properties for AlpineN2 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for AlpineN2 {
+    /// The global minimum in one dimension, see `minimum` for other dimensionalities
+    const MINIMUM: f64 = -ALPINE_N2_MAX;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 1.0;
+        for xi in x {
+            fx *= xi.sqrt()*xi.sin();
+        }
+        -fx
+    }
+
+    /// The global minimum depends on the dimensionality
+    fn minimum(n: usize) -> f64 {
+        -ALPINE_N2_MAX.powi(n as i32)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![ALPINE_N2_ARGMAX; n]
+    }
+}
+
+#[cfg(test)]
+mod alpine_n2_tests {
+    use super::{AlpineN2 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-9)
+    }
+
+    #[test]
+    fn check_scaled_minimum() {
+        assert!((F::minimum(2) + 7.885600724127536).abs() < 1e-9);
+        for n in [1, 5, 10] {
+            let fx = F::f(F::minimizer(n));
+            assert!((fx/F::minimum(n) - 1.0).abs() < 1e-12);
+        }
+    }
+}
+
+/// This is the Xin-She Yang N.1 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). Each term is weighted by a coefficient drawn uniformly
+/// from [0, 1], so the function carries its coefficients as data and is evaluated through the
+/// `DynSingleObjective` trait. The number of coefficients fixes the dimensionality.
+pub struct XinSheYangN1 {
+    epsilon: Vec<f64>,
+}
+
+impl NDimensional for XinSheYangN1 {}
+impl UnConstrained for XinSheYangN1 {}
+
+impl Bounded for XinSheYangN1 {
+    /// The bounds of the canonical Xin-She Yang N.1 optimization problem are [-5, 5].
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl Properties for XinSheYangN1 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+}
+
+impl XinSheYangN1 {
+    /// This function creates the function with the coefficients `epsilon`
+    pub fn new(epsilon: Vec<f64>) -> Self {
+        XinSheYangN1 { epsilon }
+    }
+
+    /// This function creates the function in `n` dimensions, drawing the coefficients from `rng`
+    #[cfg(feature = "sampling")]
+    pub fn random<R: rand::Rng>(n: usize, rng: &mut R) -> Self {
+        Self::new((0..n).map(|_| rng.gen_range(0.0..1.0)).collect())
+    }
+
+    /// This function returns the coefficients
+    pub fn epsilon(&self) -> &[f64] {
+        &self.epsilon
+    }
+}
+
+impl DynSingleObjective for XinSheYangN1 {
+    fn f(&self, x: &[f64]) -> f64 {
+        assert_eq!(x.len(), self.epsilon.len(), "Xin-She Yang N.1 requires {} inputs", self.epsilon.len());
+        let mut fx = 0.0;
+        for (i, (xi, ei)) in x.iter().zip(self.epsilon.iter()).enumerate() {
+            fx += ei*xi.abs().powi(i as i32 + 1);
+        }
+        fx
+    }
+
+    fn minimum(&self, _n: usize) -> f64 {
+        0.0
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+
+    fn bounds(&self, _n: usize) -> (f64, f64) {
+        Self::BOUNDS
+    }
+
+    fn dim(&self) -> Option<usize> {
+        Some(self.epsilon.len())
+    }
+}
+
+#[cfg(test)]
+mod xin_she_yang_n1_tests {
+    use super::{XinSheYangN1 as F, DynSingleObjective};
+
+    #[test]
+    fn check_origin() {
+        for epsilon in [vec![0.5, 0.5], vec![0.1, 0.9, 0.3], vec![1.0; 10]] {
+            let f = F::new(epsilon);
+            let n = f.dim().unwrap();
+            assert_eq!(f.f(&f.minimizer(n)), f.minimum(n));
+        }
+    }
+
+    #[test]
+    fn check_value() {
+        let f = F::new(vec![0.5, 0.25]);
+        assert_eq!(f.f(&[-2.0, 2.0]), 2.0);
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn check_random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..10 {
+            let f = F::random(5, &mut rng);
+            assert!(f.epsilon().iter().all(|&ei| (0.0..1.0).contains(&ei)));
+            assert_eq!(f.f(&f.minimizer(5)), 0.0);
+            assert!(f.f(&[1.0; 5]) > 0.0);
+        }
+    }
+}
+
+/// This is the Xin-She Yang N.2 function.
+///
+/// The function is borrowed from [here](http://benchmarkfcns.xyz/benchmarkfcns/xinsheyangn2fcn.html).
+/// The function accepts a vector with an arbitrary number of inputs, and is not differentiable at
+/// the global minimizer.
+pub struct XinSheYangN2 {}
+
+impl NDimensional for XinSheYangN2 {}
+impl UnConstrained for XinSheYangN2 {}
+
+impl Bounded for XinSheYangN2 {
+    /// The bounds of the canonical Xin-She Yang N.2 optimization problem are [-2 pi, 2 pi].
+    const BOUNDS: (f64, f64) = (-2.0*core::f64::consts::PI, 2.0*core::f64::consts::PI);
+}
+
+impl Properties for XinSheYangN2 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for XinSheYangN2 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut abs_sum = 0.0;
+        let mut sine_sum = 0.0;
+        for xi in x {
+            abs_sum += xi.abs();
+            sine_sum += xi.powi(2).sin();
+        }
+        abs_sum*(-sine_sum).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod xin_she_yang_n2_tests {
+    use super::{XinSheYangN2 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+}
+
+/// This is the Xin-She Yang N.4 function.
+///
+/// The function is borrowed from [here](http://benchmarkfcns.xyz/benchmarkfcns/xinsheyangn4fcn.html).
+/// The function accepts a vector with an arbitrary number of inputs.
+pub struct XinSheYangN4 {}
+
+impl NDimensional for XinSheYangN4 {}
+impl UnConstrained for XinSheYangN4 {}
+
+impl Bounded for XinSheYangN4 {
+    /// The bounds of the canonical Xin-She Yang N.4 optimization problem are [-10, 10].
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl Properties for XinSheYangN4 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for XinSheYangN4 {
+    /// The global minimum is constant and minus one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut sine_sum = 0.0;
+        let mut square_sum = 0.0;
+        let mut root_sine_sum = 0.0;
+        for xi in x {
+            sine_sum += xi.sin().powi(2);
+            square_sum += xi.powi(2);
+            root_sine_sum += xi.abs().sqrt().sin().powi(2);
+        }
+        (sine_sum - (-square_sum).exp())*(-root_sine_sum).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod xin_she_yang_n4_tests {
+    use super::{XinSheYangN4 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-9)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-9)
+    }
+}
+
+/// This is the Bent Cigar function.
+///
+/// The function is borrowed from the CEC 2017 benchmark suite. The function accepts a vector with an
+/// arbitrary number of inputs, and is ill-conditioned, since a single direction is far less
+/// sensitive than all of the others. Together with `Discus`, which reverses the conditioning, it
+/// forms a pair for testing whether an optimizer is invariant to the scaling of the problem.
+pub struct BentCigar {}
+
+impl NDimensional for BentCigar {}
+impl UnConstrained for BentCigar {}
+
+impl Bounded for BentCigar {
+    /// The bounds of the canonical Bent Cigar optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for BentCigar {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+
+    fn condition_number(_n: usize) -> Option<f64> {
+        Some(1e6)
+    }
+}
+
+impl SingleObjective for BentCigar {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = x[0].powi(2);
+        for xi in x.iter().skip(1) {
+            fx += 1e6*xi.powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod bent_cigar_tests {
+    use super::{BentCigar as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_conditioning() {
+        assert_eq!(F::f(vec![1.0, 0.0, 0.0]), 1.0);
+        assert_eq!(F::f(vec![0.0, 1.0, 0.0]), 1e6);
+    }
+}
+
+/// This is the Discus function.
+///
+/// The function is borrowed from the CEC 2013 benchmark suite. The function accepts a vector with an
+/// arbitrary number of inputs, and is ill-conditioned, since a single direction is far more
+/// sensitive than all of the others. Together with `BentCigar`, which reverses the conditioning, it
+/// forms a pair for testing whether an optimizer is invariant to the scaling of the problem.
+pub struct Discus {}
+
+impl NDimensional for Discus {}
+impl UnConstrained for Discus {}
+
+impl Bounded for Discus {
+    /// The bounds of the canonical Discus optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Discus {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+
+    fn condition_number(_n: usize) -> Option<f64> {
+        Some(1e6)
+    }
+}
+
+impl SingleObjective for Discus {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 1e6*x[0].powi(2);
+        for xi in x.iter().skip(1) {
+            fx += xi.powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod discus_tests {
+    use super::{Discus as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_conditioning() {
+        assert_eq!(F::f(vec![1.0, 0.0, 0.0]), 1e6);
+        assert_eq!(F::f(vec![0.0, 1.0, 0.0]), 1.0);
+    }
+}
+
+/// This is the Ellipsoid function.
+///
+/// The function is borrowed from the BBOB benchmark suite. The function accepts a vector with an
+/// arbitrary number of inputs, and the scaling of each axis increases smoothly from `1` to `10^6`.
+/// In one dimension, the single axis has a scaling of `1`.
+pub struct Ellipsoid {}
+
+impl NDimensional for Ellipsoid {}
+impl UnConstrained for Ellipsoid {}
+
+impl Bounded for Ellipsoid {
+    /// The bounds of the canonical Ellipsoid optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Ellipsoid {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+
+    fn condition_number(n: usize) -> Option<f64> {
+        if n > 1 { Some(1e6) } else { Some(1.0) }
+    }
+}
+
+impl SingleObjective for Ellipsoid {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let n = x.len();
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            let scaling = if n > 1 { 10f64.powf(6.0*(i as f64)/((n - 1) as f64)) } else { 1.0 };
+            fx += scaling*xi.powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod ellipsoid_tests {
+    use super::{Ellipsoid as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_one_d() {
+        F::check_minimizer(1);
+        assert_eq!(F::f(vec![3.0]), 9.0);
+    }
+
+    #[test]
+    fn check_scaling() {
+        assert_eq!(F::f(vec![1.0, 0.0, 0.0]), 1.0);
+        assert!((F::f(vec![0.0, 1.0, 0.0]) - 1e3).abs() < 1e-9);
+        assert!((F::f(vec![0.0, 0.0, 1.0]) - 1e6).abs() < 1e-6);
+    }
+}
+
+/// This is the Different Powers function.
+///
+/// The function is borrowed from the CEC 2013 benchmark suite. The function accepts a vector with an
+/// arbitrary number of inputs, and the exponent of each axis increases from `2` to `6`, so the
+/// function becomes flatter near the minimizer along later axes. In one dimension, the single axis
+/// has an exponent of `2`.
+pub struct DifferentPowers {}
+
+impl NDimensional for DifferentPowers {}
+impl UnConstrained for DifferentPowers {}
+
+impl Bounded for DifferentPowers {
+    /// The bounds of the canonical Different Powers optimization problem are [-5, 5].
+    const BOUNDS: (f64, f64) = (-5.0, 5.0);
+}
+
+impl Properties for DifferentPowers {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+
+    fn num_local_minima(_n: usize) -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl SingleObjective for DifferentPowers {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let n = x.len();
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            let exponent = if n > 1 { 2.0 + 4.0*(i as f64)/((n - 1) as f64) } else { 2.0 };
+            fx += xi.abs().powf(exponent);
+        }
+        fx.sqrt()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod different_powers_tests {
+    use super::{DifferentPowers as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_one_d() {
+        F::check_minimizer(1);
+        assert_eq!(F::f(vec![-3.0]), 3.0);
+    }
+
+    #[test]
+    fn check_powers() {
+        assert_eq!(F::f(vec![2.0, 0.0, 0.0]), 2.0);
+        assert_eq!(F::f(vec![0.0, 2.0, 0.0]), 4.0);
+        assert_eq!(F::f(vec![0.0, 0.0, 2.0]), 8.0);
+    }
+}
+
+/// This function is the 2-D kernel of the Schaffer F6 function
+fn schaffer_f6_kernel(x: f64, y: f64) -> f64 {
+    let square_sum = x.powi(2) + y.powi(2);
+    0.5 + (square_sum.sqrt().sin().powi(2) - 0.5)/(1.0 + 0.001*square_sum).powi(2)
+}
+
+/// This is the Expanded Schaffer F6 function.
+///
+/// The function is borrowed from the CEC 2005 benchmark suite. The function accepts a vector with an
+/// arbitrary number of inputs, and applies the 2-D Schaffer F6 kernel to each pair of consecutive
+/// variables, including the pair formed by the last and the first variable.
+pub struct ExpandedSchafferF6 {}
+
+impl NDimensional for ExpandedSchafferF6 {}
+impl UnConstrained for ExpandedSchafferF6 {}
+
+impl Bounded for ExpandedSchafferF6 {
+    /// The bounds of the canonical Expanded Schaffer F6 optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for ExpandedSchafferF6 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for ExpandedSchafferF6 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let n = x.len();
+        let mut fx = 0.0;
+        for i in 0..n {
+            fx += schaffer_f6_kernel(x[i], x[(i + 1) % n]);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod expanded_schaffer_f6_tests {
+    use super::{ExpandedSchafferF6 as F, schaffer_f6_kernel, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_wrap_around() {
+        let (a, b, c) = (1.0, -2.0, 3.5);
+        let expected = schaffer_f6_kernel(a, b) + schaffer_f6_kernel(b, c) + schaffer_f6_kernel(c, a);
+        assert!((F::f(vec![a, b, c]) - expected).abs() < 1e-12);
+        assert!((F::f(vec![a, 0.0, 0.0]) - 2.0*schaffer_f6_kernel(a, 0.0)).abs() < 1e-12);
+    }
+}
+
+/// This function is the penalty term `u(x, a, k, m)` of the penalized functions, which is zero
+/// inside `[-a, a]` and grows as `k*(|x| - a)^m` outside of it
+fn penalty(xi: f64, a: f64, k: f64, m: i32) -> f64 {
+    if xi > a {
+        k*(xi - a).powi(m)
+    } else if xi < -a {
+        k*(-xi - a).powi(m)
+    } else {
+        0.0
+    }
+}
+
+/// This is the first Penalized function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and is evaluated on the transformed variables `y_i = 1 + (x_i + 1)/4`, so the global
+/// minimizer is at `x_i = -1`.
+pub struct Penalized1 {}
+
+impl NDimensional for Penalized1 {}
+impl UnConstrained for Penalized1 {}
+
+impl Bounded for Penalized1 {
+    /// The bounds of the canonical Penalized optimization problem are [-50, 50].
+    const BOUNDS: (f64, f64) = (-50.0, 50.0);
+}
+
+impl Properties for Penalized1 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Penalized1 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let pi = core::f64::consts::PI;
+        let n = x.len();
+        let y: Vec<f64> = x.iter().map(|xi| 1.0 + (xi + 1.0)/4.0).collect();
+        let mut fx = 10.0*(pi*y[0]).sin().powi(2) + (y[n - 1] - 1.0).powi(2);
+        for i in 0..(n - 1) {
+            fx += (y[i] - 1.0).powi(2)*(1.0 + 10.0*(pi*y[i + 1]).sin().powi(2));
+        }
+        fx *= pi/(n as f64);
+        for xi in x {
+            fx += penalty(xi, 10.0, 100.0, 4);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![-1.0; n]
+    }
+}
+
+#[cfg(test)]
+mod penalized1_tests {
+    use super::{Penalized1 as F, penalty, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-12)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-12)
+    }
+
+    #[test]
+    fn check_penalty() {
+        assert_eq!(penalty(5.0, 10.0, 100.0, 4), 0.0);
+        assert_eq!(penalty(12.0, 10.0, 100.0, 4), 1600.0);
+        assert_eq!(penalty(-12.0, 10.0, 100.0, 4), 1600.0);
+    }
+}
+
+/// This is the second Penalized function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and shares its penalty term with `Penalized1`.
+pub struct Penalized2 {}
+
+impl NDimensional for Penalized2 {}
+impl UnConstrained for Penalized2 {}
+
+impl Bounded for Penalized2 {
+    /// The bounds of the canonical Penalized optimization problem are [-50, 50].
+    const BOUNDS: (f64, f64) = (-50.0, 50.0);
+}
+
+impl Properties for Penalized2 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Penalized2 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let pi = core::f64::consts::PI;
+        let n = x.len();
+        let mut fx = (3.0*pi*x[0]).sin().powi(2)
+            + (x[n - 1] - 1.0).powi(2)*(1.0 + (2.0*pi*x[n - 1]).sin().powi(2));
+        for i in 0..(n - 1) {
+            fx += (x[i] - 1.0).powi(2)*(1.0 + (3.0*pi*x[i + 1]).sin().powi(2));
+        }
+        fx *= 0.1;
+        for xi in x {
+            fx += penalty(xi, 5.0, 100.0, 4);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![1.0; n]
+    }
+}
+
+#[cfg(test)]
+mod penalized2_tests {
+    use super::{Penalized2 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-12)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-12)
+    }
+
+    #[test]
+    fn check_penalized() {
+        assert!(F::f(vec![6.0, 1.0]) > 100.0);
+    }
+}
+
+/// This is the Quartic function with noise, also known as De Jong N.4.
+///
+/// The function is borrowed from [here](http://benchmarkfcns.xyz/benchmarkfcns/quarticfcn.html).
+/// The function accepts a vector with an arbitrary number of inputs, and adds a random term drawn
+/// uniformly from [0, 1) to the deterministic part on every evaluation. Since each noisy evaluation
+/// needs a random number generator, `SingleObjective::f` evaluates the deterministic part, which is
+/// also available as `Quartic::noiseless`, and the noisy function is `Quartic::f_noisy`.
+pub struct Quartic {}
+
+impl NDimensional for Quartic {}
+impl UnConstrained for Quartic {}
+
+impl Bounded for Quartic {
+    /// The bounds of the canonical Quartic optimization problem are [-1.28, 1.28].
+    const BOUNDS: (f64, f64) = (-1.28, 1.28);
+}
+
+impl Properties for Quartic {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl Quartic {
+    /// Function for evaluating the deterministic part
+    pub fn noiseless(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            fx += ((i + 1) as f64)*xi.powi(4);
+        }
+        fx
+    }
+
+    /// Function for evaluating with noise, drawing the random term from `rng`
+    #[cfg(feature = "sampling")]
+    pub fn f_noisy<R: rand::Rng>(x: Vec<f64>, rng: &mut R) -> f64 {
+        Self::noiseless(x) + rng.gen_range(0.0..1.0)
+    }
+}
+
+impl SingleObjective for Quartic {
+    /// The global minimum of the deterministic part is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating the deterministic part
+    fn f(x: Vec<f64>) -> f64 {
+        Self::noiseless(x)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod quartic_tests {
+    use super::{Quartic as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_noiseless() {
+        assert_eq!(F::noiseless(F::minimizer(F::LOW_D)), F::MINIMUM);
+        assert_eq!(F::noiseless(F::minimizer(F::HIGH_D)), F::MINIMUM);
+        assert_eq!(F::noiseless(vec![1.0, -1.0]), 3.0);
+        assert_eq!(F::f(vec![1.0, -1.0]), 3.0);
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn check_noisy() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let values: Vec<f64> = (0..100).map(|_| F::f_noisy(F::minimizer(5), &mut rng)).collect();
+        assert!(values.iter().all(|&fx| (0.0..1.0).contains(&fx)));
+        assert!(values.iter().any(|&fx| fx != values[0]));
+    }
+}
+
+/// This is the Step function, also known as De Jong N.3.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and is piecewise constant, so its gradient is zero almost everywhere. The global minimum
+/// is attained on the whole plateau where every `|x_i| < 0.5`.
+pub struct Step {}
+
+impl NDimensional for Step {}
+impl UnConstrained for Step {}
+
+impl Bounded for Step {
+    /// The bounds of the canonical Step optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Step {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for Step {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for xi in x {
+            fx += (xi + 0.5).floor().powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod step_tests {
+    use super::{Step as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_plateau() {
+        assert_eq!(F::f(vec![0.4; F::HIGH_D]), F::MINIMUM);
+        assert_eq!(F::f(vec![-0.4, 0.2]), F::MINIMUM);
+        assert_eq!(F::f(vec![0.5, -1.6]), 5.0);
+    }
+}
+
+/// This is the Schwefel 2.22 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and is not differentiable where any coordinate is zero. Despite the shared name, it is
+/// unrelated to the multimodal function usually called the Schwefel function.
+pub struct Schwefel222 {}
+
+impl NDimensional for Schwefel222 {}
+impl UnConstrained for Schwefel222 {}
+
+impl Bounded for Schwefel222 {
+    /// The bounds of the canonical Schwefel 2.22 optimization problem are [-10, 10].
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl Properties for Schwefel222 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for Schwefel222 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut sum = 0.0;
+        let mut prod = 1.0;
+        for xi in x {
+            sum += xi.abs();
+            prod *= xi.abs();
+        }
+        sum + prod
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod schwefel222_tests {
+    use super::{Schwefel222 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_value() {
+        assert_eq!(F::f(vec![1.0, -2.0, 3.0]), 12.0);
+    }
+}
+
+/// This is the Schwefel 1.2 function, also known as the Rotated Hyper-Ellipsoid function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and sums the squares of the prefix sums of the input, which couples every coordinate
+/// with all the coordinates before it.
+pub struct Schwefel12 {}
+
+impl NDimensional for Schwefel12 {}
+impl UnConstrained for Schwefel12 {}
+
+impl Bounded for Schwefel12 {
+    /// The bounds of the canonical Schwefel 1.2 optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Schwefel12 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for Schwefel12 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        let mut prefix = 0.0;
+        for xi in x {
+            prefix += xi;
+            fx += prefix.powi(2);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod schwefel12_tests {
+    use super::{Schwefel12 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_naive() {
+        let x: Vec<f64> = (0..F::HIGH_D).map(|i| ((i as f64)*0.7).sin()*50.0).collect();
+        let mut naive = 0.0;
+        for i in 0..x.len() {
+            let mut inner = 0.0;
+            for xj in x.iter().take(i + 1) {
+                inner += xj;
+            }
+            naive += inner*inner;
+        }
+        assert!((F::f(x) - naive).abs() < 1e-9*naive);
+    }
+}
+
+/// This is the Schwefel 2.21 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and returns the largest absolute coordinate, so it is not differentiable wherever that
+/// largest coordinate is shared or zero.
+pub struct Schwefel221 {}
+
+impl NDimensional for Schwefel221 {}
+impl UnConstrained for Schwefel221 {}
+
+impl Bounded for Schwefel221 {
+    /// The bounds of the canonical Schwefel 2.21 optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Schwefel221 {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = false;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for Schwefel221 {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        x.iter().fold(0.0, |fx, xi| xi.abs().max(fx))
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod schwefel221_tests {
+    use super::{Schwefel221 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_value() {
+        assert_eq!(F::f(vec![1.0, -3.0, 2.0]), 3.0);
+    }
+}
+
+/// This is the Sphere function constrained to a ball.
+///
+/// This is an N-dimensional constrained benchmark, which minimizes the Sphere function subject to
+/// the single inequality `sum x_i^2 - r^2 <= 0`. The constraint uses a radius of one, and
+/// `inequality_constraints_with_radius` allows other radii. The minimizer at the origin lies in the
+/// interior of the feasible region for every positive radius.
+pub struct SphereBallConstrained {}
+
+impl SphereBallConstrained {
+    /// The radius of the ball used by the `Constrained` implementation
+    pub const RADIUS: f64 = 1.0;
+
+    /// This function returns the value of the inequality constraint for a ball of radius `r`
+    pub fn inequality_constraints_with_radius(x: Vec<f64>, r: f64) -> Vec<f64> {
+        let mut fx: Vec<f64> = vec![0.0; Self::NG];
+        fx[0] = x.iter().map(|xi| xi.powi(2)).sum::<f64>() - r.powi(2);
+        fx
+    }
+}
+
+impl NDimensional for SphereBallConstrained {}
+impl UnBounded for SphereBallConstrained {}
+
+impl DynBounded for SphereBallConstrained {
+    fn bounds(_n: usize) -> (f64, f64) {
+        <SphereBallConstrained as UnBounded>::BOUNDS
+    }
+}
+
+impl Constrained for SphereBallConstrained {
+    const NH: usize = 0;
+    const NG: usize = 1;
+
+    fn equality_constraints(_x: Vec<f64>) -> Vec<f64> {
+        vec![0.0; Self::NH]
+    }
+
+    fn inequality_constraints(x: Vec<f64>) -> Vec<f64> {
+        Self::inequality_constraints_with_radius(x, Self::RADIUS)
+    }
+}
+
+impl Properties for SphereBallConstrained {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+
+    fn condition_number(_n: usize) -> Option<f64> {
+        Some(1.0)
+    }
+}
+
+impl SingleObjective for SphereBallConstrained {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut f = 0f64;
+        for xi in x {
+            f += xi.powi(2);
+        }
+        f
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod sphere_ball_constrained_tests {
+    use super::{SphereBallConstrained as F, NDimensional, SingleObjective, Constrained};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_feasible() {
+        assert!(F::g(F::minimizer(F::LOW_D))[0] < 0.0);
+        assert!(F::g(vec![0.5, 0.5])[0] < 0.0);
+        assert_eq!(F::g(vec![0.6, 0.8])[0], 0.0);
+        assert!(F::h(vec![0.6, 0.8]).is_empty());
+    }
+
+    #[test]
+    fn check_sign_flip() {
+        let direction = [0.6, 0.8];
+        for r in [0.5, 1.0, 3.0] {
+            let inside: Vec<f64> = direction.iter().map(|d| 0.99*r*d).collect();
+            let outside: Vec<f64> = direction.iter().map(|d| 1.01*r*d).collect();
+            assert!(F::inequality_constraints_with_radius(inside, r)[0] < 0.0);
+            assert!(F::inequality_constraints_with_radius(outside, r)[0] > 0.0);
+        }
+    }
+}
+
+/// This is the G1 constrained function.
+///
+/// The function is borrowed from Runarsson and Yao, "Stochastic Ranking for Constrained
+/// Evolutionary Optimization" (2000), where it is the first problem of the standard constrained
+/// suite. This function is specifically 13 dimensional, with a concave quadratic objective and
+/// nine linear inequality constraints, six of which are active at the optimum.
+pub struct G1 {}
+
+impl FixedDimensional for G1 {
+    const D: usize = 13;
+}
+
+impl BoxBounded for G1 {
+    const BOUNDS: &'static [(f64, f64)] = &[
+        (0.0, 1.0), (0.0, 1.0), (0.0, 1.0), (0.0, 1.0), (0.0, 1.0), (0.0, 1.0), (0.0, 1.0),
+        (0.0, 1.0), (0.0, 1.0), (0.0, 100.0), (0.0, 100.0), (0.0, 100.0), (0.0, 1.0),
+    ];
+}
+
+impl DynBounded for G1 {
+    /// The bounds differ between variables, so these are the smallest bounds containing all of them
+    fn bounds(_n: usize) -> (f64, f64) {
+        Self::enclosing_bounds()
+    }
+
+    fn in_dyn_bounds(x: Vec<f64>) -> bool {
+        <Self as BoxBounded>::in_bounds(x)
+    }
+}
+
+impl Domain for G1 {
+    fn dim() -> Option<usize> {
+        Self::required_dim()
+    }
+
+    fn box_bounds(_n: usize) -> Vec<(f64, f64)> {
+        Self::BOUNDS.to_vec()
+    }
+}
+
+impl Constrained for G1 {
+    const NH: usize = 0;
+    const NG: usize = 9;
+
+    fn equality_constraints(_x: Vec<f64>) -> Vec<f64> {
+        vec![0.0; Self::NH]
+    }
+
+    fn inequality_constraints(x: Vec<f64>) -> Vec<f64> {
+        let mut fx: Vec<f64> = vec![0.0; Self::NG];
+        fx[0] = 2.0*x[0] + 2.0*x[1] + x[9] + x[10] - 10.0;
+        fx[1] = 2.0*x[0] + 2.0*x[2] + x[9] + x[11] - 10.0;
+        fx[2] = 2.0*x[1] + 2.0*x[2] + x[10] + x[11] - 10.0;
+        fx[3] = -8.0*x[0] + x[9];
+        fx[4] = -8.0*x[1] + x[10];
+        fx[5] = -8.0*x[2] + x[11];
+        fx[6] = -2.0*x[3] - x[4] + x[9];
+        fx[7] = -2.0*x[5] - x[6] + x[10];
+        fx[8] = -2.0*x[7] - x[8] + x[11];
+        fx
+    }
+}
+
+impl Properties for G1 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for G1 {
+    /// The global minimum is constant and negative fifteen
+    const MINIMUM: f64 = -15.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        let mut fx = 0.0;
+        for xi in x.iter().take(4) {
+            fx += 5.0*xi - 5.0*xi.powi(2);
+        }
+        for xi in x.iter().skip(4) {
+            fx -= xi;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 1.0]
+    }
+}
+
+#[cfg(test)]
+mod g1_tests {
+    use super::{G1 as F, FixedDimensional, SingleObjective, Constrained, BoxBounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::D)
+    }
+
+    #[test]
+    fn check_optimum() {
+        let x = F::minimizer(F::D);
+        assert!(F::in_bounds(x.clone()));
+        let g = F::g(x.clone());
+        assert_eq!(g.len(), F::NG);
+        assert!(g.iter().all(|&gi| gi <= 0.0));
+        assert_eq!(g.iter().filter(|&&gi| gi == 0.0).count(), 6);
+        assert!(F::h(x).is_empty());
+    }
+}
+
+/// This is the G6 constrained function.
+///
+/// The function is borrowed from Runarsson and Yao, "Stochastic Ranking for Constrained
+/// Evolutionary Optimization" (2000). This function is specifically 2 dimensional, with a cubic
+/// objective and two inequality constraints that leave a thin, crescent-shaped feasible region.
+/// Both constraints are active at the optimum.
+pub struct G6 {}
+
+impl FixedDimensional for G6 {
+    const D: usize = 2;
+}
+
+impl BoxBounded for G6 {
+    const BOUNDS: &'static [(f64, f64)] = &[(13.0, 100.0), (0.0, 100.0)];
+}
+
+impl DynBounded for G6 {
+    /// The bounds differ between variables, so these are the smallest bounds containing all of them
+    fn bounds(_n: usize) -> (f64, f64) {
+        Self::enclosing_bounds()
+    }
+
+    fn in_dyn_bounds(x: Vec<f64>) -> bool {
+        <Self as BoxBounded>::in_bounds(x)
+    }
+}
+
+impl Domain for G6 {
+    fn dim() -> Option<usize> {
+        Self::required_dim()
+    }
+
+    fn box_bounds(_n: usize) -> Vec<(f64, f64)> {
+        Self::BOUNDS.to_vec()
+    }
+}
+
+impl Constrained for G6 {
+    const NH: usize = 0;
+    const NG: usize = 2;
+
+    fn equality_constraints(_x: Vec<f64>) -> Vec<f64> {
+        vec![0.0; Self::NH]
+    }
+
+    fn inequality_constraints(x: Vec<f64>) -> Vec<f64> {
+        let mut fx: Vec<f64> = vec![0.0; Self::NG];
+        fx[0] = -(x[0] - 5.0).powi(2) - (x[1] - 5.0).powi(2) + 100.0;
+        fx[1] = (x[0] - 6.0).powi(2) + (x[1] - 5.0).powi(2) - 82.81;
+        fx
+    }
+}
+
+impl Properties for G6 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for G6 {
+    /// The global minimum is constant and approximately -6961.81388
+    const MINIMUM: f64 = -6961.81387558015;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(x.clone());
+        (x[0] - 10.0).powi(3) + (x[1] - 20.0).powi(3)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(_n: usize) -> Vec<f64> {
+        vec![14.095, 0.8429607892154796]
+    }
+}
+
+#[cfg(test)]
+mod g6_tests {
+    use super::{G6 as F, FixedDimensional, SingleObjective, Constrained, BoxBounded};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::D, 1e-8)
+    }
+
+    #[test]
+    fn check_optimum() {
+        let x = F::minimizer(F::D);
+        assert!(F::in_bounds(x.clone()));
+        let g = F::g(x);
+        assert_eq!(g.len(), F::NG);
+        assert!(g.iter().all(|gi| gi.abs() < 1e-9));
+    }
+}
+
+/// These are the coordinates of the minimizer of the Michalewicz function with `m = 10`, which is
+/// separable, so each coordinate is found by a one-dimensional search
+const MICHALEWICZ_ARGMIN: [f64; 10] = [
+    2.202905522322922, FRAC_PI_2, 1.284991572556124, 1.9230584684916017, 1.7204697743164843,
+    FRAC_PI_2, 1.4544139719057032, 1.7560865203469778, 1.6557174167275757, FRAC_PI_2,
+];
+
+/// These are the global minima of the Michalewicz function with `m = 10` in one to ten dimensions
+const MICHALEWICZ_MINIMA: [f64; 10] = [
+    -0.8013034100985531, -1.8013034100985532, -2.76039467999456, -3.698857098466644, -4.687658179088149,
+    -5.687658179088149, -6.680885314444029, -7.66375735071624, -8.660151715641344, -9.660151715641344,
+];
+
+/// This is the Michalewicz function.
+///
+/// The function is borrowed from [here](https://www.sfu.ca/~ssurjano/michal.html). The function
+/// accepts a vector with an arbitrary number of inputs, and has `n!` local minima. The steepness
+/// `m` controls the width of its valleys, with larger values making them harder to find. The global
+/// minimum is only known numerically, so it is tabulated for up to ten dimensions with `m = 10`;
+/// `minimum` returns negative infinity in other dimensionalities, where `minimizer` panics.
+pub struct Michalewicz {}
+
+impl NDimensional for Michalewicz {}
+impl UnConstrained for Michalewicz {}
+
+impl Bounded for Michalewicz {
+    /// The bounds of the canonical Michalewicz optimization problem are [0, pi].
+    const BOUNDS: (f64, f64) = (0.0, core::f64::consts::PI);
+}
+
+impl Properties for Michalewicz {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+/// This is the configuration of the tunable parameters of the Michalewicz function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MichalewiczConfig {
+    /// The steepness of the valleys
+    pub m: i32,
+}
+
+impl Default for MichalewiczConfig {
+    /// The canonical parameter is `m = 10`
+    fn default() -> Self {
+        MichalewiczConfig { m: 10 }
+    }
+}
+
+impl Michalewicz {
+    /// Function for evaluating with the parameters in `config`
+    pub fn f_config(x: Vec<f64>, config: &MichalewiczConfig) -> f64 {
+        Self::f_with_m(x, config.m)
+    }
+
+    /// Function for evaluating with a specific steepness `m`
+    pub fn f_with_m(x: Vec<f64>, m: i32) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            let i = (i + 1) as f64;
+            fx -= xi.sin()*(i*xi.powi(2)/core::f64::consts::PI).sin().powi(2*m);
+        }
+        fx
+    }
+}
+
+impl SingleObjective for Michalewicz {
+    /// The global minimum in one dimension, see `minimum` for other dimensionalities
+    const MINIMUM: f64 = MICHALEWICZ_MINIMA[0];
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::f_config(x, &MichalewiczConfig::default())
+    }
+
+    /// The global minimum depends on the dimensionality, and is negative infinity where it is not
+    /// tabulated
+    fn minimum(n: usize) -> f64 {
+        match n {
+            1..=10 => MICHALEWICZ_MINIMA[n - 1],
+            _ => f64::NEG_INFINITY,
+        }
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        if n > MICHALEWICZ_ARGMIN.len() {
+            panic!("The minimizer of the Michalewicz function is only tabulated for up to {} dimensions, but {} were requested.", MICHALEWICZ_ARGMIN.len(), n);
+        }
+        MICHALEWICZ_ARGMIN[..n].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod michalewicz_tests {
+    use super::{Michalewicz as F, MichalewiczConfig, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-12)
+    }
+
+    #[test]
+    fn check_table() {
+        assert!((F::minimum(2) + 1.8013).abs() < 1e-4);
+        assert!((F::minimum(5) + 4.687658).abs() < 1e-6);
+        assert!((F::minimum(10) + 9.66015).abs() < 1e-5);
+        for n in 1..=10 {
+            F::check_minimizer_within(n, 1e-12);
+        }
+        assert_eq!(F::minimum(11), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_untabulated() {
+        F::minimizer(F::HIGH_D);
+    }
+
+    #[test]
+    fn check_config() {
+        let x = vec![1.0, 2.0, 0.5];
+        assert_eq!(F::f_config(x.clone(), &MichalewiczConfig::default()), F::f(x.clone()));
+        assert_eq!(F::f_config(x.clone(), &MichalewiczConfig { m: 1 }), F::f_with_m(x, 1));
+    }
+}
+
+/// This is the Csendes function, also known as the EX3 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs. Each term contains `sin(1/x_i)`, which is undefined at zero, so a zero coordinate
+/// contributes its limit of zero.
+pub struct Csendes {}
+
+impl NDimensional for Csendes {}
+impl UnConstrained for Csendes {}
+
+impl Bounded for Csendes {
+    /// The bounds of the canonical Csendes optimization problem are [-1, 1].
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl Properties for Csendes {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Csendes {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for xi in x {
+            if xi != 0.0 {
+                fx += xi.powi(6)*(2.0 + (1.0/xi).sin());
+            }
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod csendes_tests {
+    use super::{Csendes as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_zero_coordinate() {
+        assert!(F::f(vec![0.0, 0.5]).is_finite());
+        assert_eq!(F::f(vec![0.0, 0.5]), F::f(vec![0.5]));
+        assert!(F::f(vec![1e-3, 0.0]) > 0.0);
+    }
+}
+
+/// This is the Deb N.1 function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and its global minima form a regular lattice where every `x_i` is an odd multiple of
+/// `0.1`. The minimizer returned is the one with every `x_i = 0.1`.
+pub struct DebN1 {}
+
+impl NDimensional for DebN1 {}
+impl UnConstrained for DebN1 {}
+
+impl Bounded for DebN1 {
+    /// The bounds of the canonical Deb N.1 optimization problem are [-1, 1].
+    const BOUNDS: (f64, f64) = (-1.0, 1.0);
+}
+
+impl Properties for DebN1 {
+    const SEPARABLE: bool = true;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for DebN1 {
+    /// The global minimum is constant and negative one
+    const MINIMUM: f64 = -1.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let n = x.len() as f64;
+        let mut fx = 0.0;
+        for xi in x {
+            fx += (5.0*core::f64::consts::PI*xi).sin().powi(6);
+        }
+        -fx/n
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.1; n]
+    }
+}
+
+#[cfg(test)]
+mod deb_n1_tests {
+    use super::{DebN1 as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-9)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-9)
+    }
+
+    #[test]
+    fn check_lattice() {
+        assert!((F::f(vec![-0.9, 0.3, 0.7]) - F::MINIMUM).abs() < 1e-9);
+        assert!(F::f(vec![0.0, 0.0]).abs() < 1e-12);
+    }
+}
+
+/// This is the Keane bump function.
+///
+/// The function is borrowed from Keane, "Experiences with Optimizers in Structural Design" (1994).
+/// The function accepts a vector with an arbitrary number of inputs, and is subject to the
+/// inequalities `prod x_i >= 0.75` and `sum x_i <= 7.5n`. The global minimum lies on the boundary
+/// of the first constraint and is only known numerically, so it is given for two dimensions;
+/// `minimum` returns negative infinity in other dimensionalities, where `minimizer` panics. The
+/// function is taken to be zero at the origin, where its quotient is undefined.
+pub struct Keane {}
+
+impl NDimensional for Keane {}
+
+impl Bounded for Keane {
+    /// The bounds of the canonical Keane optimization problem are [0, 10].
+    const BOUNDS: (f64, f64) = (0.0, 10.0);
+}
+
+impl Constrained for Keane {
+    const NH: usize = 0;
+    const NG: usize = 2;
+
+    fn equality_constraints(_x: Vec<f64>) -> Vec<f64> {
+        vec![0.0; Self::NH]
+    }
+
+    fn inequality_constraints(x: Vec<f64>) -> Vec<f64> {
+        let n = x.len() as f64;
+        let mut fx: Vec<f64> = vec![0.0; Self::NG];
+        fx[0] = 0.75 - x.iter().product::<f64>();
+        fx[1] = x.iter().sum::<f64>() - 7.5*n;
+        fx
+    }
+}
+
+impl Properties for Keane {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Keane {
+    /// The global minimum in two dimensions, see `minimum` for other dimensionalities
+    const MINIMUM: f64 = -0.36497974587065657;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut sum = 0.0;
+        let mut prod = 1.0;
+        let mut weighted = 0.0;
+        for (i, xi) in x.iter().enumerate() {
+            sum += xi.cos().powi(4);
+            prod *= xi.cos().powi(2);
+            weighted += ((i + 1) as f64)*xi.powi(2);
+        }
+        if weighted == 0.0 {
+            return 0.0;
+        }
+        -(sum - 2.0*prod).abs()/weighted.sqrt()
+    }
+
+    /// The global minimum depends on the dimensionality, and is negative infinity where it is not
+    /// known
+    fn minimum(n: usize) -> f64 {
+        match n {
+            2 => Self::MINIMUM,
+            _ => f64::NEG_INFINITY,
+        }
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        if n != 2 {
+            panic!("The minimizer of the Keane function is only known in 2 dimensions, but {} were requested.", n);
+        }
+        vec![1.6008604449663095, 0.46849805200589106]
+    }
+}
+
+#[cfg(test)]
+mod keane_tests {
+    use super::{Keane as F, SingleObjective, Constrained};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(2, 1e-12)
+    }
+
+    #[test]
+    fn check_constraints() {
+        let g = F::g(F::minimizer(2));
+        assert!(g[0].abs() < 1e-12 && g[1] < 0.0);
+        assert!(F::g(vec![1.0, 1.0]).iter().all(|&gi| gi <= 0.0));
+        let g = F::g(vec![0.5, 0.5]);
+        assert!(g[0] > 0.0 && g[1] < 0.0);
+        let g = F::g(vec![10.0, 10.0]);
+        assert!(g[0] < 0.0 && g[1] > 0.0);
+    }
+
+    #[test]
+    fn check_finite() {
+        for x in [vec![1.0, 1.0], vec![3.0, 0.25, 8.0], vec![10.0; 5]] {
+            let fx = F::f(x);
+            assert!(fx.is_finite() && fx <= 0.0);
+        }
+        assert_eq!(F::f(vec![0.0; 2]), 0.0);
+        assert_eq!(F::f(vec![0.0; 5]), 0.0);
+    }
+}
+
+/// This is the Periodic function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and has a local minimum at every multiple of `pi` in each coordinate. The narrow
+/// Gaussian dip at the origin makes the global minimum of `0.9` only slightly deeper than the
+/// surrounding local minima of `1`.
+pub struct Periodic {}
+
+impl NDimensional for Periodic {}
+impl UnConstrained for Periodic {}
+
+impl Bounded for Periodic {
+    /// The bounds of the canonical Periodic optimization problem are [-10, 10].
+    const BOUNDS: (f64, f64) = (-10.0, 10.0);
+}
+
+impl Properties for Periodic {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Periodic {
+    /// The global minimum is constant and 0.9
+    const MINIMUM: f64 = 0.9;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut sine_sum = 0.0;
+        let mut square_sum = 0.0;
+        for xi in x {
+            sine_sum += xi.sin().powi(2);
+            square_sum += xi.powi(2);
+        }
+        1.0 + sine_sum - 0.1*(-square_sum).exp()
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod periodic_tests {
+    use super::{Periodic as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer_within(F::LOW_D, 1e-9)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer_within(F::HIGH_D, 1e-9)
+    }
+
+    #[test]
+    fn check_non_zero_minimum() {
+        assert_eq!(F::f(F::minimizer(3)), 0.9);
+        let pi = core::f64::consts::PI;
+        assert!(F::f(vec![pi, 0.0]) > F::MINIMUM);
+        assert!(F::f(vec![0.1, 0.0]) > F::MINIMUM);
+    }
+}
+
+/// This is the Pathological function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with at least two inputs,
+/// and sums a Schaffer-like term over each pair of consecutive coordinates, which makes it highly
+/// multimodal and non-separable. Besides the origin, the global minimum is attained wherever every
+/// coordinate equals the same multiple of `pi/sqrt(101)`.
+pub struct Pathological {}
+
+impl NDimensional for Pathological {
+    const MIN_D: usize = 2;
+}
+impl UnConstrained for Pathological {}
+
+impl Bounded for Pathological {
+    /// The bounds of the canonical Pathological optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for Pathological {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = true;
+}
+
+impl SingleObjective for Pathological {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for pair in x.windows(2) {
+            let (xi, xj) = (pair[0], pair[1]);
+            let numerator = (100.0*xi.powi(2) + xj.powi(2)).sqrt().sin().powi(2) - 0.5;
+            let denominator = 1.0 + 0.001*(xi.powi(2) - 2.0*xi*xj + xj.powi(2)).powi(2);
+            fx += 0.5 + numerator/denominator;
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+
+    /// This function returns the global minimizers within the bounds, which lie on the diagonal
+    fn minimizers(n: usize) -> Vec<Vec<f64>> {
+        let step = core::f64::consts::PI/101f64.sqrt();
+        let k_max = (Self::BOUNDS.1/step) as i64;
+        (-k_max..=k_max).map(|k| vec![(k as f64)*step; n]).collect()
+    }
+}
+
+#[cfg(test)]
+mod pathological_tests {
+    use super::{Pathological as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_positive() {
+        assert!(F::f(vec![1.0, 2.0, -3.0]) > F::MINIMUM);
+        assert!(F::f(vec![0.0, 0.1]) > F::MINIMUM);
+    }
+
+    #[test]
+    fn check_diagonal_minimizers() {
+        let minimizers = F::minimizers(3);
+        assert_eq!(minimizers.len(), 639);
+        assert!(minimizers.contains(&F::minimizer(3)));
+        for x in minimizers {
+            assert!(F::is_minimizer(x, 1e-9));
+        }
+        assert!(F::dist_to_minimizer(vec![0.3, 0.3]) < 0.02);
+    }
+}
+
+/// This is the Brown function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with at least two inputs.
+/// Each coordinate is raised to a power that grows with the square of its neighbour, so the
+/// function is extremely steep towards the upper bound and overflows soon beyond it.
+pub struct Brown {}
+
+impl NDimensional for Brown {
+    const MIN_D: usize = 2;
+}
+impl UnConstrained for Brown {}
+
+impl Bounded for Brown {
+    /// The bounds of the canonical Brown optimization problem are [-1, 4].
+    const BOUNDS: (f64, f64) = (-1.0, 4.0);
+}
+
+impl Properties for Brown {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl SingleObjective for Brown {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut fx = 0.0;
+        for pair in x.windows(2) {
+            let (si, sj) = (pair[0].powi(2), pair[1].powi(2));
+            // The exponents are at least one, so a zero base contributes zero
+            fx += si.powf(sj + 1.0) + sj.powf(si + 1.0);
+        }
+        fx
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod brown_tests {
+    use super::{Brown as F, NDimensional, SingleObjective};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_values() {
+        assert_eq!(F::f(vec![0.0, 1.0]), 1.0);
+        assert_eq!(F::f(vec![1.0, 1.0, 1.0]), 4.0);
+        assert!(F::f(vec![4.0, 4.0]).is_finite());
+    }
+}
+
+/// This is the Chung-Reynolds function.
+///
+/// The function is borrowed from Jamil and Yang, "A Literature Survey of Benchmark Functions for
+/// Global Optimization Problems" (2013). The function accepts a vector with an arbitrary number of
+/// inputs, and is the square of the Sphere function, so it is convex but very flat around the
+/// minimizer, where its Hessian vanishes.
+pub struct ChungReynolds {}
+
+impl NDimensional for ChungReynolds {}
+impl UnConstrained for ChungReynolds {}
+
+impl Bounded for ChungReynolds {
+    /// The bounds of the canonical Chung-Reynolds optimization problem are [-100, 100].
+    const BOUNDS: (f64, f64) = (-100.0, 100.0);
+}
+
+impl Properties for ChungReynolds {
+    const SEPARABLE: bool = false;
+    const DIFFERENTIABLE: bool = true;
+    const MULTIMODAL: bool = false;
+}
+
+impl ChungReynolds {
+    /// This function returns the analytic gradient, `4*(sum x_j^2)*x_i`
+    pub fn analytic_gradient(x: Vec<f64>) -> Vec<f64> {
+        Self::check_input(&x);
+        let square_sum: f64 = x.iter().map(|xi| xi.powi(2)).sum();
+        x.iter().map(|xi| 4.0*square_sum*xi).collect()
+    }
+}
+
+impl SingleObjective for ChungReynolds {
+    /// The global minimum is constant and zero
+    const MINIMUM: f64 = 0.0;
+
+    /// Function for evaluating
+    fn f(x: Vec<f64>) -> f64 {
+        Self::check_input(&x);
+        let mut square_sum = 0.0;
+        for xi in x {
+            square_sum += xi.powi(2);
+        }
+        square_sum.powi(2)
+    }
+
+    /// This function returns the minimizer (argument that will return the global minimum
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+#[cfg(test)]
+mod chung_reynolds_tests {
+    use super::{ChungReynolds as F, NDimensional, SingleObjective, check_gradient};
+
+    #[test]
+    fn low_d() {
+        F::check_minimizer(F::LOW_D)
+    }
+
+    #[test]
+    fn high_d() {
+        F::check_minimizer(F::HIGH_D)
+    }
+
+    #[test]
+    fn check_analytic_gradient() {
+        for x in [vec![1.0, -2.0], vec![0.5, 0.25, -1.5], vec![3.0; 5]] {
+            let analytic = F::analytic_gradient(x.clone());
+            let scale = analytic.iter().fold(1.0, |m: f64, gi| m.max(gi.abs()));
+            assert!(check_gradient::<F>(x, &analytic, 1e-6*scale));
+        }
+        assert_eq!(F::analytic_gradient(F::minimizer(3)), vec![0.0; 3]);
+    }
+}
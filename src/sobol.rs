@@ -0,0 +1,118 @@
+//! This module contains a generator for the Sobol low-discrepancy sequence, which is used by
+//! `Bounded::sobol`
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+
+/// This is the number of bits in each coordinate of the sequence
+const BITS: usize = 32;
+
+/// These are the primitive polynomials and initial direction numbers of Joe and Kuo
+/// (new-joe-kuo-6.21201) for every dimension after the first, as `(s, a, m)`
+const DIRECTIONS: [(usize, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// This is the largest dimensionality supported by the generator
+pub(crate) const MAX_DIM: usize = DIRECTIONS.len() + 1;
+
+/// This function returns the direction integers of dimension `j`
+fn direction_integers(j: usize) -> [u32; BITS] {
+    let mut v = [0u32; BITS];
+    if j == 0 {
+        for (k, vk) in v.iter_mut().enumerate() {
+            *vk = 1 << (BITS - 1 - k);
+        }
+        return v;
+    }
+    let (s, a, m) = DIRECTIONS[j - 1];
+    for k in 0..BITS {
+        if k < s {
+            v[k] = m[k] << (BITS - 1 - k);
+        } else {
+            v[k] = v[k - s] ^ (v[k - s] >> s);
+            for i in 1..s {
+                if (a >> (s - 1 - i)) & 1 == 1 {
+                    v[k] ^= v[k - i];
+                }
+            }
+        }
+    }
+    v
+}
+
+/// This function returns the first `count` points of the Sobol sequence in the `n`-dimensional
+/// unit hypercube, starting from the origin
+pub(crate) fn sobol_unit(n: usize, count: usize) -> Vec<Vec<f64>> {
+    assert!(n <= MAX_DIM, "The Sobol sequence is only available in up to {} dimensions, but {} were requested", MAX_DIM, n);
+    let directions: Vec<[u32; BITS]> = (0..n).map(direction_integers).collect();
+    let scale = (1u64 << BITS) as f64;
+    let mut x = vec![0u32; n];
+    let mut points: Vec<Vec<f64>> = Vec::with_capacity(count);
+    for i in 0..count {
+        if i > 0 {
+            let c = (i - 1).trailing_ones() as usize;
+            for (xj, vj) in x.iter_mut().zip(directions.iter()) {
+                *xj ^= vj[c];
+            }
+        }
+        points.push(x.iter().map(|&xj| (xj as f64)/scale).collect());
+    }
+    points
+}
+
+#[cfg(test)]
+mod sobol_tests {
+    use super::{sobol_unit, MAX_DIM};
+
+    #[test]
+    fn check_first_points() {
+        let points = sobol_unit(3, 8);
+        let expected = [
+            [0.0, 0.0, 0.0],
+            [0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25],
+            [0.25, 0.75, 0.75],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375],
+        ];
+        for (point, expected) in points.iter().zip(expected.iter()) {
+            assert_eq!(point.as_slice(), expected);
+        }
+    }
+
+    #[test]
+    fn check_stratified() {
+        let count = 256;
+        let points = sobol_unit(MAX_DIM, count);
+        for j in 0..MAX_DIM {
+            let mut occupied = vec![false; count];
+            for point in points.iter() {
+                let stratum = (point[j]*(count as f64)) as usize;
+                assert!(!occupied[stratum]);
+                occupied[stratum] = true;
+            }
+        }
+    }
+}
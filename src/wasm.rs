@@ -0,0 +1,49 @@
+//! This module contains entry points for evaluating the benchmark functions from JavaScript, which
+//! select functions by their name in the registry
+
+use wasm_bindgen::prelude::*;
+use crate::registry::get_single;
+
+/// This function evaluates the single-objective function registered under `name` at `x`, returning
+/// `NaN` if no function is registered under that name
+#[wasm_bindgen]
+pub fn eval_single(name: &str, x: Vec<f64>) -> f64 {
+    match get_single(name) {
+        Some(function) => function.f(&x),
+        None => f64::NAN,
+    }
+}
+
+/// This function returns the lower and upper bounds in `n` dimensions of the single-objective
+/// function registered under `name`, or an empty vector if no function is registered under that name
+#[wasm_bindgen]
+pub fn bounds(name: &str, n: usize) -> Vec<f64> {
+    match get_single(name) {
+        Some(function) => {
+            let (lower, upper) = function.bounds(n);
+            vec![lower, upper]
+        }
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod wasm_tests {
+    use super::{eval_single, bounds};
+    use crate::{Rastrigin, SingleObjective};
+
+    #[test]
+    fn check_rastrigin() {
+        let x = vec![0.5, -1.0, 2.0];
+        assert_eq!(eval_single("rastrigin", x.clone()), Rastrigin::f(x));
+        assert_eq!(eval_single("rastrigin", vec![0.0; 2]), 0.0);
+        assert_eq!(bounds("rastrigin", 3), vec![-5.12, 5.12]);
+        assert_eq!(bounds("perm", 3), vec![-3.0, 3.0]);
+    }
+
+    #[test]
+    fn check_unknown() {
+        assert!(eval_single("not_a_function", vec![0.0; 2]).is_nan());
+        assert!(bounds("not_a_function", 2).is_empty());
+    }
+}
@@ -0,0 +1,782 @@
+//! This module contains wrappers that transform single-objective functions, such as shifting them
+//! away from the origin. The wrappers carry their configuration as data, so they are evaluated
+//! through the `DynSingleObjective` trait.
+
+use core::marker::PhantomData;
+#[cfg(feature = "sampling")]
+use core::cell::RefCell;
+use crate::{SingleObjective, Constrained, Domain};
+use crate::registry::DynSingleObjective;
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+
+/// This is a shifted version of a single-objective function.
+///
+/// The function is evaluated as `f(x) = F::f(x - shift)`, so the minimizer moves from
+/// `F::minimizer(n)` to `F::minimizer(n) + shift`. This follows the tradition of the CEC benchmark
+/// suites, and prevents optimizers from exploiting optima that sit at the origin.
+pub struct Shifted<F> {
+    shift: Vec<f64>,
+    function: PhantomData<F>,
+}
+
+impl<F: SingleObjective + Domain> Shifted<F> {
+    /// This function creates a new shifted function from a shift vector
+    pub fn new(shift: Vec<f64>) -> Self {
+        Shifted {
+            shift,
+            function: PhantomData,
+        }
+    }
+
+    /// This function returns the shift vector
+    pub fn shift(&self) -> &[f64] {
+        &self.shift
+    }
+
+    /// This function is used to check inputs
+    fn check_input(&self, n: usize) {
+        if n != self.shift.len() {
+            panic!("A vector with size {} was used with a shift of dimensionality {}.", n, self.shift.len());
+        }
+    }
+}
+
+impl<F: SingleObjective + Domain> DynSingleObjective for Shifted<F> {
+    fn f(&self, x: &[f64]) -> f64 {
+        self.check_input(x.len());
+        F::f(x.iter().zip(self.shift.iter()).map(|(xi, si)| xi - si).collect())
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        F::minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        self.check_input(n);
+        F::minimizer(n).iter().zip(self.shift.iter()).map(|(xi, si)| xi + si).collect()
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        F::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        F::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        F::dim()
+    }
+}
+
+/// This is a rotated version of a single-objective function.
+///
+/// The function is evaluated as `f(x) = F::f(R*x)` for an orthogonal matrix `R`, so the minimizer
+/// moves from `F::minimizer(n)` to `R^T*F::minimizer(n)`. Rotation breaks the separability of the
+/// function, which stops coordinate-wise optimizers from exploiting axis-aligned structure.
+pub struct Rotated<F> {
+    rotation: Vec<Vec<f64>>,
+    function: PhantomData<F>,
+}
+
+impl<F: SingleObjective + Domain> Rotated<F> {
+    /// This function creates a new rotated function from an orthogonal matrix, given as rows
+    pub fn new(rotation: Vec<Vec<f64>>) -> Self {
+        if !is_orthogonal(&rotation, 1e-9) {
+            panic!("The rotation matrix must be square and orthogonal.");
+        }
+        Rotated {
+            rotation,
+            function: PhantomData,
+        }
+    }
+
+    /// This function creates a new rotated function from a random rotation of dimensionality `n`
+    #[cfg(feature = "sampling")]
+    pub fn random<R: rand::Rng>(n: usize, rng: &mut R) -> Self {
+        Self::new(random_rotation(n, rng))
+    }
+
+    /// This function returns the rotation matrix
+    pub fn rotation(&self) -> &[Vec<f64>] {
+        &self.rotation
+    }
+
+    /// This function is used to check inputs
+    fn check_input(&self, n: usize) {
+        if n != self.rotation.len() {
+            panic!("A vector with size {} was used with a rotation of dimensionality {}.", n, self.rotation.len());
+        }
+    }
+}
+
+impl<F: SingleObjective + Domain> DynSingleObjective for Rotated<F> {
+    fn f(&self, x: &[f64]) -> f64 {
+        self.check_input(x.len());
+        F::f(rotate(&self.rotation, x))
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        F::minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        self.check_input(n);
+        rotate_transpose(&self.rotation, &F::minimizer(n))
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        F::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        F::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        F::dim()
+    }
+}
+
+/// This function computes `R*x`
+fn rotate(rotation: &[Vec<f64>], x: &[f64]) -> Vec<f64> {
+    rotation.iter().map(|row| row.iter().zip(x.iter()).map(|(rij, xj)| rij*xj).sum()).collect()
+}
+
+/// This function computes `R^T*x`
+fn rotate_transpose(rotation: &[Vec<f64>], x: &[f64]) -> Vec<f64> {
+    let mut y = vec![0.0; x.len()];
+    for (row, xi) in rotation.iter().zip(x.iter()) {
+        for (yj, rij) in y.iter_mut().zip(row.iter()) {
+            *yj += rij*xi;
+        }
+    }
+    y
+}
+
+/// This function checks that a matrix is square and that `R*R^T` is the identity within `tol`
+fn is_orthogonal(rotation: &[Vec<f64>], tol: f64) -> bool {
+    let n = rotation.len();
+    if rotation.iter().any(|row| row.len() != n) {
+        return false;
+    }
+    for i in 0..n {
+        for j in 0..n {
+            let dot: f64 = rotation[i].iter().zip(rotation[j].iter()).map(|(a, b)| a*b).sum();
+            let identity = if i == j { 1.0 } else { 0.0 };
+            if (dot - identity).abs() > tol {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// This function generates a random orthogonal matrix by Gram-Schmidt orthonormalization of random
+/// vectors
+#[cfg(feature = "sampling")]
+fn random_rotation<R: rand::Rng>(n: usize, rng: &mut R) -> Vec<Vec<f64>> {
+    let mut rotation: Vec<Vec<f64>> = Vec::with_capacity(n);
+    while rotation.len() < n {
+        let mut v: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        for row in rotation.iter() {
+            let dot: f64 = v.iter().zip(row.iter()).map(|(a, b)| a*b).sum();
+            for (vi, ri) in v.iter_mut().zip(row.iter()) {
+                *vi -= dot*ri;
+            }
+        }
+        let norm = v.iter().map(|vi| vi.powi(2)).sum::<f64>().sqrt();
+        if norm > 1e-6 {
+            rotation.push(v.iter().map(|vi| vi/norm).collect());
+        }
+    }
+    rotation
+}
+
+/// This is a noisy version of a single-objective function.
+///
+/// The function is evaluated as `f(x) = F::f(x) + N(0, sigma)`, with the Gaussian noise drawn from
+/// the wrapped random number generator. This is useful for studying the robustness of optimizers
+/// to stochastic evaluations, while `noiseless` still gives the clean value for scoring.
+#[cfg(feature = "sampling")]
+pub struct Noisy<F, R> {
+    sigma: f64,
+    rng: RefCell<R>,
+    function: PhantomData<F>,
+}
+
+#[cfg(feature = "sampling")]
+impl<F: SingleObjective + Domain, R: rand::Rng> Noisy<F, R> {
+    /// This function creates a new noisy function with noise standard deviation `sigma`
+    pub fn new(sigma: f64, rng: R) -> Self {
+        Noisy {
+            sigma,
+            rng: RefCell::new(rng),
+            function: PhantomData,
+        }
+    }
+
+    /// This function returns the noise standard deviation
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Function for evaluating the objective function without noise
+    pub fn noiseless(&self, x: &[f64]) -> f64 {
+        F::f(x.to_vec())
+    }
+}
+
+#[cfg(feature = "sampling")]
+impl<F: SingleObjective + Domain, R: rand::Rng> DynSingleObjective for Noisy<F, R> {
+    fn f(&self, x: &[f64]) -> f64 {
+        self.noiseless(x) + self.sigma*standard_normal(&mut *self.rng.borrow_mut())
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        F::minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        F::minimizer(n)
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        F::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        F::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        F::dim()
+    }
+}
+
+/// This function draws a sample from the standard normal distribution using the Box-Muller transform
+#[cfg(feature = "sampling")]
+fn standard_normal<R: rand::Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2.0*u1.ln()).sqrt()*(2.0*core::f64::consts::PI*u2).cos()
+}
+
+/// This is a transformed version of a single-objective function, in the style of the CEC benchmark
+/// suites.
+///
+/// The function is evaluated as `f(x) = F::f(R*(x - shift)) + bias`, where the shift, the orthogonal
+/// rotation `R` and the bias are all optional, and are set with a builder:
+/// `Transformed::<F>::new().shift(..).rotate(..).bias(..)`.
+pub struct Transformed<F> {
+    shift: Option<Vec<f64>>,
+    rotation: Option<Vec<Vec<f64>>>,
+    bias: f64,
+    function: PhantomData<F>,
+}
+
+impl<F: SingleObjective + Domain> Default for Transformed<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: SingleObjective + Domain> Transformed<F> {
+    /// This function creates a new transformed function, with no shift, no rotation and no bias
+    pub fn new() -> Self {
+        Transformed {
+            shift: None,
+            rotation: None,
+            bias: 0.0,
+            function: PhantomData,
+        }
+    }
+
+    /// This function sets the shift vector
+    pub fn shift(mut self, shift: Vec<f64>) -> Self {
+        self.shift = Some(shift);
+        self.check_consistent();
+        self
+    }
+
+    /// This function sets the rotation, from an orthogonal matrix given as rows
+    pub fn rotate(mut self, rotation: Vec<Vec<f64>>) -> Self {
+        if !is_orthogonal(&rotation, 1e-9) {
+            panic!("The rotation matrix must be square and orthogonal.");
+        }
+        self.rotation = Some(rotation);
+        self.check_consistent();
+        self
+    }
+
+    /// This function sets the bias, which is added to the value of the function
+    pub fn bias(mut self, bias: f64) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    /// This function returns the dimensionality fixed by the shift or the rotation, if any
+    fn transform_dim(&self) -> Option<usize> {
+        self.shift.as_ref().map(|shift| shift.len()).or(self.rotation.as_ref().map(|rotation| rotation.len()))
+    }
+
+    /// This function is used to check that the shift and the rotation have the same dimensionality
+    fn check_consistent(&self) {
+        if let (Some(shift), Some(rotation)) = (&self.shift, &self.rotation) {
+            if shift.len() != rotation.len() {
+                panic!("A shift of dimensionality {} was used with a rotation of dimensionality {}.", shift.len(), rotation.len());
+            }
+        }
+    }
+
+    /// This function is used to check inputs
+    fn check_input(&self, n: usize) {
+        if let Some(d) = self.transform_dim() {
+            if n != d {
+                panic!("A vector with size {} was used with a transform of dimensionality {}.", n, d);
+            }
+        }
+    }
+}
+
+impl<F: SingleObjective + Domain> DynSingleObjective for Transformed<F> {
+    fn f(&self, x: &[f64]) -> f64 {
+        self.check_input(x.len());
+        let mut z = x.to_vec();
+        if let Some(shift) = &self.shift {
+            z = z.iter().zip(shift.iter()).map(|(zi, si)| zi - si).collect();
+        }
+        if let Some(rotation) = &self.rotation {
+            z = rotate(rotation, &z);
+        }
+        F::f(z) + self.bias
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        F::minimum(n) + self.bias
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        self.check_input(n);
+        let mut x = F::minimizer(n);
+        if let Some(rotation) = &self.rotation {
+            x = rotate_transpose(rotation, &x);
+        }
+        if let Some(shift) = &self.shift {
+            x = x.iter().zip(shift.iter()).map(|(xi, si)| xi + si).collect();
+        }
+        x
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        F::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        F::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        F::dim()
+    }
+}
+
+/// This is a component of a composition function, given as the function, its width `sigma`, its
+/// scale `lambda`, its bias, and the shift that locates it
+pub type Component = (Box<dyn DynSingleObjective>, f64, f64, f64, Vec<f64>);
+
+/// This is a composition function, in the style of the CEC benchmark suites.
+///
+/// The function blends several shifted components, with weights that are Gaussian in the distance
+/// to the optimum of each component, as `f(x) = sum w_i*(lambda_i*g_i(x - shift_i) + bias_i)`. Close
+/// to the optimum of a component, its weight approaches one, so the landscape has a local optimum
+/// near each component, and the global optimum is at the one with the lowest biased minimum.
+pub struct Composition {
+    components: Vec<Component>,
+}
+
+impl Composition {
+    /// This function creates a new composition function from its components, which must all be
+    /// shifted by vectors of the same dimensionality
+    pub fn new(components: Vec<Component>) -> Self {
+        if components.is_empty() {
+            panic!("A composition function requires at least one component.");
+        }
+        let n = components[0].4.len();
+        if components.iter().any(|component| component.4.len() != n) {
+            panic!("All components of a composition function must have shifts of dimensionality {}.", n);
+        }
+        Composition { components }
+    }
+
+    /// This function returns the optimum of each component
+    fn optima(&self) -> Vec<Vec<f64>> {
+        self.components
+            .iter()
+            .map(|(function, _, _, _, shift)| {
+                function.minimizer(shift.len()).iter().zip(shift.iter()).map(|(xi, si)| xi + si).collect()
+            })
+            .collect()
+    }
+
+    /// This function returns the normalized weight of each component at `x`
+    pub fn weights(&self, x: &[f64]) -> Vec<f64> {
+        let n = x.len() as f64;
+        let square_distances: Vec<f64> = self.optima()
+            .iter()
+            .map(|optimum| x.iter().zip(optimum.iter()).map(|(xi, oi)| (xi - oi).powi(2)).sum())
+            .collect();
+        if let Some(i) = square_distances.iter().position(|&d| d == 0.0) {
+            let mut weights = vec![0.0; self.components.len()];
+            weights[i] = 1.0;
+            return weights;
+        }
+        let weights: Vec<f64> = square_distances
+            .iter()
+            .zip(self.components.iter())
+            .map(|(d, component)| (-d/(2.0*n*component.1.powi(2))).exp()/d.sqrt())
+            .collect();
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+            let equal = 1.0/(weights.len() as f64);
+            return vec![equal; weights.len()];
+        }
+        weights.iter().map(|wi| wi/total).collect()
+    }
+
+    /// This function is used to check inputs
+    fn check_input(&self, n: usize) {
+        if n != self.components[0].4.len() {
+            panic!("A vector with size {} was used with components of dimensionality {}.", n, self.components[0].4.len());
+        }
+    }
+
+    /// This function returns the index of the component with the lowest biased minimum
+    fn best_component(&self) -> usize {
+        let n = self.components[0].4.len();
+        let mut best = 0;
+        let mut best_value = f64::INFINITY;
+        for (i, (function, _, lambda, bias, _)) in self.components.iter().enumerate() {
+            let value = lambda*function.minimum(n) + bias;
+            if value < best_value {
+                best = i;
+                best_value = value;
+            }
+        }
+        best
+    }
+}
+
+impl DynSingleObjective for Composition {
+    fn f(&self, x: &[f64]) -> f64 {
+        self.check_input(x.len());
+        let mut fx = 0.0;
+        for (wi, (function, _, lambda, bias, shift)) in self.weights(x).iter().zip(self.components.iter()) {
+            if *wi > 0.0 {
+                let z: Vec<f64> = x.iter().zip(shift.iter()).map(|(xi, si)| xi - si).collect();
+                fx += wi*(lambda*function.f(&z) + bias);
+            }
+        }
+        fx
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        let (function, _, lambda, bias, _) = &self.components[self.best_component()];
+        lambda*function.minimum(n) + bias
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        self.check_input(n);
+        self.optima().swap_remove(self.best_component())
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        self.components[0].0.bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        self.components[0].0.box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        Some(self.components[0].4.len())
+    }
+}
+
+/// This is a penalized version of a constrained single-objective function.
+///
+/// The function is evaluated as `f(x) = F::f(x) + mu*F::total_violation(x)`, which agrees with
+/// `F::f` on the feasible region and grows with the violation outside it. This lets unconstrained
+/// optimizers be run on the constrained benchmarks.
+pub struct Penalized<F> {
+    mu: f64,
+    function: PhantomData<F>,
+}
+
+impl<F: SingleObjective + Constrained + Domain> Penalized<F> {
+    /// This function creates a new penalized function from a penalty coefficient
+    pub fn new(mu: f64) -> Self {
+        Penalized {
+            mu,
+            function: PhantomData,
+        }
+    }
+
+    /// This function returns the penalty coefficient
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+}
+
+impl<F: SingleObjective + Constrained + Domain> DynSingleObjective for Penalized<F> {
+    fn f(&self, x: &[f64]) -> f64 {
+        <F as SingleObjective>::f(x.to_vec()) + self.mu*F::total_violation(x.to_vec())
+    }
+
+    fn minimum(&self, n: usize) -> f64 {
+        <F as SingleObjective>::minimum(n)
+    }
+
+    fn minimizer(&self, n: usize) -> Vec<f64> {
+        <F as SingleObjective>::minimizer(n)
+    }
+
+    fn bounds(&self, n: usize) -> (f64, f64) {
+        F::bounds(n)
+    }
+
+    fn box_bounds(&self, n: usize) -> Vec<(f64, f64)> {
+        F::box_bounds(n)
+    }
+
+    fn dim(&self) -> Option<usize> {
+        F::dim()
+    }
+}
+
+#[cfg(all(test, feature = "sampling"))]
+mod noisy_tests {
+    use super::{Noisy, DynSingleObjective};
+    use crate::{Rastrigin, SingleObjective};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn check_noiseless() {
+        let f = Noisy::<Rastrigin, _>::new(0.5, StdRng::seed_from_u64(0));
+        let x = [0.5, -1.0];
+        assert_eq!(f.noiseless(&x), <Rastrigin as SingleObjective>::f(x.to_vec()));
+        assert_ne!(f.f(&x), f.noiseless(&x));
+    }
+
+    #[test]
+    fn check_mean() {
+        let f = Noisy::<Rastrigin, _>::new(1.0, StdRng::seed_from_u64(0));
+        let x = [0.5, -1.0];
+        let samples = 10000;
+        let mut mean = 0.0;
+        for _ in 0..samples {
+            mean += f.f(&x)/(samples as f64);
+        }
+        assert!((mean - f.noiseless(&x)).abs() < 0.05);
+    }
+}
+
+#[cfg(test)]
+mod rotated_tests {
+    use super::{Rotated, DynSingleObjective};
+    use crate::{Sphere, Rosenbrock, SingleObjective};
+
+    fn rotation() -> Vec<Vec<f64>> {
+        let (s, c) = (0.6, 0.8);
+        vec![vec![c, -s, 0.0], vec![s, c, 0.0], vec![0.0, 0.0, 1.0]]
+    }
+
+    #[test]
+    fn check_minimum() {
+        let f = Rotated::<Sphere>::new(rotation());
+        assert_eq!(f.f(&f.minimizer(3)), f.minimum(3));
+        let x = [1.0, -2.0, 0.5];
+        assert!((f.f(&x) - <Sphere as SingleObjective>::f(x.to_vec())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_minimizer() {
+        let f = Rotated::<Rosenbrock>::new(rotation());
+        let expected = [0.8 + 0.6, -0.6 + 0.8, 1.0];
+        for (xi, ei) in f.minimizer(3).iter().zip(expected.iter()) {
+            assert!((xi - ei).abs() < 1e-12);
+        }
+        assert!((f.f(&f.minimizer(3)) - f.minimum(3)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_not_orthogonal() {
+        Rotated::<Sphere>::new(vec![vec![1.0, 1.0], vec![0.0, 1.0]]);
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn check_random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let f = Rotated::<Rosenbrock>::random(10, &mut rng);
+        assert_eq!(f.rotation().len(), 10);
+        assert!((f.f(&f.minimizer(10)) - f.minimum(10)).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod shifted_tests {
+    use super::{Shifted, DynSingleObjective};
+    use crate::{Sphere, Rosenbrock, SingleObjective};
+
+    #[test]
+    fn check_minimizer() {
+        let shift = vec![1.5, -2.0, 0.25];
+        let f = Shifted::<Sphere>::new(shift.clone());
+        assert_eq!(f.minimizer(3), shift);
+        assert_eq!(f.f(&shift), f.minimum(3));
+    }
+
+    #[test]
+    fn check_shifted_minimizer() {
+        let shift = vec![1.5, -2.0, 0.25];
+        let f = Shifted::<Rosenbrock>::new(shift);
+        assert_eq!(f.minimizer(3), vec![2.5, -1.0, 1.25]);
+        assert_eq!(f.f(&f.minimizer(3)), f.minimum(3));
+    }
+
+    #[test]
+    fn check_value() {
+        let f = Shifted::<Rosenbrock>::new(vec![1.0, 2.0]);
+        assert_eq!(f.f(&[0.0, 0.0]), <Rosenbrock as SingleObjective>::f(vec![-1.0, -2.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_size() {
+        Shifted::<Sphere>::new(vec![1.0, 2.0]).f(&[0.0; 3]);
+    }
+}
+
+#[cfg(test)]
+mod transformed_tests {
+    use super::{Transformed, DynSingleObjective};
+    use crate::{Sphere, Rosenbrock};
+
+    fn rotation() -> Vec<Vec<f64>> {
+        let (s, c) = (0.6, 0.8);
+        vec![vec![c, -s, 0.0], vec![s, c, 0.0], vec![0.0, 0.0, 1.0]]
+    }
+
+    #[test]
+    fn check_identity() {
+        let f = Transformed::<Sphere>::new();
+        assert_eq!(f.f(&[1.0, 2.0]), 5.0);
+        assert_eq!(f.minimizer(2), vec![0.0; 2]);
+        assert_eq!(f.minimum(2), 0.0);
+    }
+
+    #[test]
+    fn check_composed_sphere() {
+        let shift = vec![1.5, -2.0, 0.25];
+        let f = Transformed::<Sphere>::new().shift(shift.clone()).rotate(rotation()).bias(100.0);
+        assert_eq!(f.minimum(3), 100.0);
+        let minimizer = f.minimizer(3);
+        for (xi, si) in minimizer.iter().zip(shift.iter()) {
+            assert!((xi - si).abs() < 1e-12);
+        }
+        assert!((f.f(&minimizer) - f.minimum(3)).abs() < 1e-12);
+        assert!(f.f(&[0.0, 0.0, 0.0]) > f.minimum(3));
+    }
+
+    #[test]
+    fn check_composed_rosenbrock() {
+        let f = Transformed::<Rosenbrock>::new().rotate(rotation()).shift(vec![1.0, 2.0, 3.0]).bias(-50.0);
+        assert!((f.f(&f.minimizer(3)) - f.minimum(3)).abs() < 1e-12);
+        assert_eq!(f.minimum(3), -50.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_inconsistent() {
+        Transformed::<Sphere>::new().shift(vec![0.0; 2]).rotate(rotation());
+    }
+}
+
+#[cfg(test)]
+mod composition_tests {
+    use super::{Composition, DynSingleObjective};
+    use crate::{Sphere, Rastrigin};
+
+    fn composition() -> Composition {
+        Composition::new(vec![
+            (Box::new(Sphere {}), 10.0, 1.0, 0.0, vec![0.0, 0.0]),
+            (Box::new(Rastrigin {}), 20.0, 1.0, 100.0, vec![3.0, 3.0]),
+        ])
+    }
+
+    #[test]
+    fn check_optima() {
+        let f = composition();
+        assert_eq!(f.f(&[0.0, 0.0]), 0.0);
+        assert_eq!(f.f(&[3.0, 3.0]), 100.0);
+        assert_eq!(f.minimizer(2), vec![0.0, 0.0]);
+        assert_eq!(f.minimum(2), 0.0);
+        assert_eq!(f.dim(), Some(2));
+    }
+
+    #[test]
+    fn check_dominant_component() {
+        let f = composition();
+        let x = [0.01, -0.02];
+        let weights = f.weights(&x);
+        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(weights[0] > 0.99);
+        let sphere = weights[0]*<Sphere as crate::SingleObjective>::f(x.to_vec());
+        assert!(f.f(&x) > sphere);
+        let x = [2.99, 3.02];
+        assert!(f.weights(&x)[1] > 0.99);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_inconsistent() {
+        Composition::new(vec![
+            (Box::new(Sphere {}), 10.0, 1.0, 0.0, vec![0.0, 0.0]),
+            (Box::new(Rastrigin {}), 20.0, 1.0, 100.0, vec![3.0]),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod penalized_tests {
+    use super::{Penalized, DynSingleObjective};
+    use crate::{RosenbrockConst1, SingleObjective, Constrained};
+
+    #[test]
+    fn check_feasible() {
+        let f = Penalized::<RosenbrockConst1>::new(10.0);
+        for x in [vec![1.0, 1.0], vec![0.0, 0.5], vec![-1.0, 0.5]] {
+            assert_eq!(RosenbrockConst1::total_violation(x.clone()), 0.0);
+            assert_eq!(f.f(&x), <RosenbrockConst1 as SingleObjective>::f(x));
+        }
+        assert_eq!(f.f(&f.minimizer(2)), f.minimum(2));
+    }
+
+    #[test]
+    fn check_infeasible() {
+        let f = Penalized::<RosenbrockConst1>::new(10.0);
+        let mut previous = 0.0;
+        for step in 1..5 {
+            let x = vec![1.0 + 0.5*(step as f64), 1.0];
+            let violation = RosenbrockConst1::total_violation(x.clone());
+            assert!(violation > previous);
+            assert_eq!(f.f(&x), <RosenbrockConst1 as SingleObjective>::f(x) + 10.0*violation);
+            previous = violation;
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! These tests define benchmark functions outside the crate, and check that `impl_domain!` is all
+//! they need to be used alongside the registered functions through `DynSingleObjective`.
+
+use ka::{impl_domain, Bounded, FixedDimensional, NDimensional, SingleObjective, Sphere, UnConstrained};
+use ka::registry::{get_single, DynSingleObjective};
+
+/// This is a sphere centered at one, bounded to [-2, 2]
+struct ShiftedBowl {}
+
+impl NDimensional for ShiftedBowl {}
+impl UnConstrained for ShiftedBowl {}
+
+impl Bounded for ShiftedBowl {
+    const BOUNDS: (f64, f64) = (-2.0, 2.0);
+}
+
+impl SingleObjective for ShiftedBowl {
+    const MINIMUM: f64 = 0.0;
+
+    fn f(x: Vec<f64>) -> f64 {
+        x.iter().map(|xi| (xi - 1.0).powi(2)).sum()
+    }
+
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![1.0; n]
+    }
+}
+
+/// This is a three-dimensional plane with a floor at minus one
+struct Floor {}
+
+impl FixedDimensional for Floor {
+    const D: usize = 3;
+}
+impl UnConstrained for Floor {}
+
+impl Bounded for Floor {
+    const BOUNDS: (f64, f64) = (0.0, 1.0);
+}
+
+impl SingleObjective for Floor {
+    const MINIMUM: f64 = -1.0;
+
+    fn f(x: Vec<f64>) -> f64 {
+        x.iter().sum::<f64>() - 1.0
+    }
+
+    fn minimizer(n: usize) -> Vec<f64> {
+        vec![0.0; n]
+    }
+}
+
+impl_domain!(ShiftedBowl, Floor);
+
+#[test]
+fn boxed() {
+    let functions: Vec<Box<dyn DynSingleObjective>> = vec![
+        Box::new(ShiftedBowl {}),
+        Box::new(Floor {}),
+        Box::new(Sphere {}),
+        get_single("rastrigin").unwrap(),
+    ];
+    for f in functions.iter() {
+        let n = f.dim().unwrap_or(4);
+        assert_eq!(f.f(&f.minimizer(n)), f.minimum(n));
+    }
+    assert_eq!((functions[0].dim(), functions[0].bounds(4)), (None, (-2.0, 2.0)));
+    assert_eq!((functions[1].dim(), functions[1].bounds(3)), (Some(3), (0.0, 1.0)));
+}
@@ -0,0 +1,25 @@
+//! These tests exercise the crate through a glob import, which brings every bounds trait into scope
+//! at once, and check that calls to their associated functions remain unambiguous.
+
+use ka::*;
+
+#[test]
+fn bounded() {
+    assert!(Rastrigin::in_bounds(vec![0.0, 5.0]));
+    assert!(!Rastrigin::in_bounds(vec![0.0, 6.0]));
+    assert!(Rastrigin::in_dyn_bounds(vec![0.0, 5.0]));
+    assert_eq!(Rastrigin::bounds(7), Rastrigin::BOUNDS);
+}
+
+#[test]
+fn dyn_bounded() {
+    assert_eq!(Perm::bounds(3), (-3.0, 3.0));
+    assert!(Perm::in_dyn_bounds(vec![1.0, -2.0]));
+    assert!(!Perm::in_dyn_bounds(vec![1.0, -3.0]));
+}
+
+#[test]
+fn unbounded() {
+    assert!(Sphere::in_bounds(vec![1e300, -1e300]));
+    assert_eq!(Sphere::bounds(2), (f64::NEG_INFINITY, f64::INFINITY));
+}
@@ -0,0 +1,17 @@
+//! These tests exercise the crate through its public interface. Running them with
+//! `--no-default-features` checks that the functions work when the crate is built without `std`.
+
+use ka::{Sphere, Ackley, Rastrigin, Viennet, SingleObjective, MultiObjective};
+
+#[test]
+fn sphere() {
+    assert_eq!(Sphere::f(vec![0.0; 3]), 0.0);
+    assert_eq!(Sphere::minimizer(3), vec![0.0; 3]);
+}
+
+#[test]
+fn transcendental() {
+    assert!(Ackley::f(Ackley::minimizer(5)).abs() < 1e-12);
+    assert!(Rastrigin::f(Rastrigin::minimizer(5)).abs() < 1e-12);
+    assert!((Viennet::f(vec![0.0, 0.0])[2] + 0.1).abs() < 1e-12);
+}